@@ -1,6 +1,8 @@
 //! Submodule defining the error enumeration which may occur when applying
 //! rules.
 
+mod object_ref;
+pub use object_ref::ObjectRef;
 mod rule_error_info;
 pub use rule_error_info::RuleErrorInfo;
 use sql_traits::traits::DatabaseLike;
@@ -0,0 +1,296 @@
+//! Submodule defining the `ObjectRef` enumeration, a structured reference to
+//! the DB object which failed a rule.
+
+use std::fmt::Display;
+
+use sql_traits::traits::{
+    CheckConstraintLike, ColumnLike, DatabaseLike, ForeignKeyLike, TableLike,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+/// Structured reference to the DB object which failed a rule.
+///
+/// Every variant carries the (optional) schema and the name of the table
+/// the object belongs to, so that violations can be reliably grouped and
+/// filtered regardless of the rule which reported them.
+///
+/// # Example
+///
+/// ```rust
+/// use sql_rules::error::ObjectRef;
+///
+/// let column = ObjectRef::Column {
+///     schema: Some("public".to_string()),
+///     table: "users".to_string(),
+///     column: "name".to_string(),
+/// };
+/// assert_eq!(column.to_string(), "public.users.name");
+/// assert_eq!(column.schema(), Some("public"));
+/// assert_eq!(column.table_name(), "users");
+/// assert_eq!(column.name(), Some("name"));
+///
+/// let foreign_key = ObjectRef::ForeignKey {
+///     schema: None,
+///     table: "posts".to_string(),
+///     name: None,
+///     host_columns: vec!["author_id".to_string()],
+///     referenced_schema: None,
+///     referenced_table: "users".to_string(),
+///     referenced_columns: vec!["id".to_string()],
+/// };
+/// assert_eq!(foreign_key.to_string(), "posts (author_id) -> users (id)");
+/// assert_eq!(foreign_key.name(), None);
+/// ```
+pub enum ObjectRef {
+    /// A table.
+    Table {
+        /// Schema of the table, if any.
+        schema: Option<String>,
+        /// Name of the table.
+        table: String,
+    },
+    /// A column of a table.
+    Column {
+        /// Schema of the table, if any.
+        schema: Option<String>,
+        /// Name of the table the column belongs to.
+        table: String,
+        /// Name of the column.
+        column: String,
+    },
+    /// A foreign key of a table.
+    ForeignKey {
+        /// Schema of the host table, if any.
+        schema: Option<String>,
+        /// Name of the host table.
+        table: String,
+        /// Name of the foreign key constraint, if any.
+        name: Option<String>,
+        /// Names of the host columns.
+        host_columns: Vec<String>,
+        /// Schema of the referenced table, if any.
+        referenced_schema: Option<String>,
+        /// Name of the referenced table.
+        referenced_table: String,
+        /// Names of the referenced columns.
+        referenced_columns: Vec<String>,
+    },
+    /// A check constraint of a table.
+    CheckConstraint {
+        /// Schema of the table, if any.
+        schema: Option<String>,
+        /// Name of the table the check constraint belongs to.
+        table: String,
+        /// Name of the check constraint, if any.
+        name: Option<String>,
+        /// Expression of the check constraint.
+        expression: String,
+    },
+    /// An index of a table.
+    Index {
+        /// Schema of the table, if any.
+        schema: Option<String>,
+        /// Name of the table the index belongs to.
+        table: String,
+        /// Name of the index, if any.
+        name: Option<String>,
+        /// Expression of the index.
+        expression: String,
+    },
+    /// A row-level security policy of a table.
+    Policy {
+        /// Schema of the table, if any.
+        schema: Option<String>,
+        /// Name of the table the policy belongs to.
+        table: String,
+        /// Name of the policy.
+        name: String,
+    },
+}
+
+impl ObjectRef {
+    /// Creates a reference to the provided table.
+    pub fn table<T: TableLike>(table: &T) -> Self {
+        ObjectRef::Table {
+            schema: table.table_schema().map(ToOwned::to_owned),
+            table: table.table_name().to_owned(),
+        }
+    }
+
+    /// Creates a reference to the provided column.
+    pub fn column<DB: DatabaseLike>(database: &DB, column: &DB::Column) -> Self {
+        let table = column.table(database);
+        ObjectRef::Column {
+            schema: table.table_schema().map(ToOwned::to_owned),
+            table: table.table_name().to_owned(),
+            column: column.column_name().to_owned(),
+        }
+    }
+
+    /// Creates a reference to the provided foreign key.
+    pub fn foreign_key<DB: DatabaseLike>(database: &DB, foreign_key: &DB::ForeignKey) -> Self {
+        let host_table = foreign_key.host_table(database);
+        let referenced_table = foreign_key.referenced_table(database);
+        ObjectRef::ForeignKey {
+            schema: host_table.table_schema().map(ToOwned::to_owned),
+            table: host_table.table_name().to_owned(),
+            name: foreign_key.foreign_key_name().map(ToOwned::to_owned),
+            host_columns: foreign_key
+                .host_columns(database)
+                .map(|column| column.column_name().to_owned())
+                .collect(),
+            referenced_schema: referenced_table.table_schema().map(ToOwned::to_owned),
+            referenced_table: referenced_table.table_name().to_owned(),
+            referenced_columns: foreign_key
+                .referenced_columns(database)
+                .map(|column| column.column_name().to_owned())
+                .collect(),
+        }
+    }
+
+    /// Creates a reference to a check constraint of the provided table.
+    pub fn check_constraint<T: TableLike>(
+        table: &T,
+        name: Option<&str>,
+        expression: &impl ToString,
+    ) -> Self {
+        ObjectRef::CheckConstraint {
+            schema: table.table_schema().map(ToOwned::to_owned),
+            table: table.table_name().to_owned(),
+            name: name.map(ToOwned::to_owned),
+            expression: expression.to_string(),
+        }
+    }
+
+    /// Returns the description of the provided check constraint used in
+    /// error messages: its quoted name, or `CHECK (expr)` when it is unnamed.
+    pub(crate) fn describe_check_constraint<DB: DatabaseLike>(
+        database: &DB,
+        constraint: &DB::CheckConstraint,
+    ) -> String {
+        match constraint.check_constraint_name() {
+            Some(name) => format!("'{name}'"),
+            None => format!("CHECK ({})", constraint.expression(database)),
+        }
+    }
+
+    /// Creates a reference to an index of the provided table.
+    pub fn index<T: TableLike>(table: &T, name: Option<&str>, expression: &impl ToString) -> Self {
+        ObjectRef::Index {
+            schema: table.table_schema().map(ToOwned::to_owned),
+            table: table.table_name().to_owned(),
+            name: name.map(ToOwned::to_owned),
+            expression: expression.to_string(),
+        }
+    }
+
+    /// Creates a reference to a policy of the provided table.
+    pub fn policy<T: TableLike>(table: &T, name: impl Into<String>) -> Self {
+        ObjectRef::Policy {
+            schema: table.table_schema().map(ToOwned::to_owned),
+            table: table.table_name().to_owned(),
+            name: name.into(),
+        }
+    }
+
+    /// Returns the schema of the table the object belongs to, if any.
+    #[must_use]
+    pub fn schema(&self) -> Option<&str> {
+        match self {
+            ObjectRef::Table { schema, .. }
+            | ObjectRef::Column { schema, .. }
+            | ObjectRef::ForeignKey { schema, .. }
+            | ObjectRef::CheckConstraint { schema, .. }
+            | ObjectRef::Index { schema, .. }
+            | ObjectRef::Policy { schema, .. } => schema.as_deref(),
+        }
+    }
+
+    /// Returns the name of the table the object belongs to.
+    #[must_use]
+    pub fn table_name(&self) -> &str {
+        match self {
+            ObjectRef::Table { table, .. }
+            | ObjectRef::Column { table, .. }
+            | ObjectRef::ForeignKey { table, .. }
+            | ObjectRef::CheckConstraint { table, .. }
+            | ObjectRef::Index { table, .. }
+            | ObjectRef::Policy { table, .. } => table,
+        }
+    }
+
+    /// Returns the schema-qualified name of the table the object belongs
+    /// to.
+    #[must_use]
+    pub fn qualified_table_name(&self) -> String {
+        qualify(self.schema(), self.table_name())
+    }
+
+    /// Returns the name of the object itself, if it has one.
+    ///
+    /// For tables this is the table name, for columns the column name, and
+    /// for constraints, indices and policies their (optional) name.
+    #[must_use]
+    pub fn name(&self) -> Option<&str> {
+        match self {
+            ObjectRef::Table { table, .. } => Some(table),
+            ObjectRef::Column { column, .. } => Some(column),
+            ObjectRef::ForeignKey { name, .. }
+            | ObjectRef::CheckConstraint { name, .. }
+            | ObjectRef::Index { name, .. } => name.as_deref(),
+            ObjectRef::Policy { name, .. } => Some(name),
+        }
+    }
+}
+
+/// Returns the schema-qualified version of the provided name.
+fn qualify(schema: Option<&str>, name: &str) -> String {
+    match schema {
+        Some(schema) => format!("{schema}.{name}"),
+        None => name.to_owned(),
+    }
+}
+
+impl Display for ObjectRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let table = self.qualified_table_name();
+        match self {
+            ObjectRef::Table { .. } => write!(f, "{table}"),
+            ObjectRef::Column { column, .. } => write!(f, "{table}.{column}"),
+            ObjectRef::ForeignKey {
+                name: Some(name), ..
+            }
+            | ObjectRef::CheckConstraint {
+                name: Some(name), ..
+            }
+            | ObjectRef::Index {
+                name: Some(name), ..
+            }
+            | ObjectRef::Policy { name, .. } => write!(f, "{table}.{name}"),
+            ObjectRef::ForeignKey {
+                name: None,
+                host_columns,
+                referenced_schema,
+                referenced_table,
+                referenced_columns,
+                ..
+            } => write!(
+                f,
+                "{table} ({}) -> {} ({})",
+                host_columns.join(", "),
+                qualify(referenced_schema.as_deref(), referenced_table),
+                referenced_columns.join(", ")
+            ),
+            ObjectRef::CheckConstraint {
+                name: None,
+                expression,
+                ..
+            } => write!(f, "{table} CHECK ({expression})"),
+            ObjectRef::Index {
+                name: None,
+                expression,
+                ..
+            } => write!(f, "{table} INDEX ({expression})"),
+        }
+    }
+}
@@ -5,7 +5,7 @@ mod builder;
 
 use std::fmt::Display;
 
-use crate::traits::RuleFailureInformation;
+use crate::{error::ObjectRef, traits::RuleFailureInformation};
 
 #[derive(Debug)]
 /// Struct implementing `RuleFailureInformation` for detailed error
@@ -14,12 +14,17 @@ use crate::traits::RuleFailureInformation;
 /// # Example
 ///
 /// ```rust
-/// use sql_rules::error::RuleErrorInfo;
+/// use sql_rules::error::{ObjectRef, RuleErrorInfo};
 /// use sql_rules::traits::RuleFailureInformation;
 ///
+/// let object = ObjectRef::Table {
+///     schema: None,
+///     table: "test_table".to_string(),
+/// };
+///
 /// let error_info: RuleErrorInfo = RuleErrorInfo::builder()
 ///     .rule("TestRule").unwrap()
-///     .object("test_table".to_string()).unwrap()
+///     .object(object.clone()).unwrap()
 ///     .message("Test message".to_string()).unwrap()
 ///     .resolution("Fix the issue".to_string()).unwrap()
 ///     .try_into()
@@ -34,7 +39,7 @@ use crate::traits::RuleFailureInformation;
 ///
 /// // Test getter methods
 /// assert_eq!(error_info.rule(), "TestRule");
-/// assert_eq!(error_info.object(), "test_table");
+/// assert_eq!(error_info.object(), &object);
 /// assert_eq!(error_info.message(), "Test message");
 /// assert_eq!(error_info.resolution(), Some("Fix the issue"));
 /// ```
@@ -42,7 +47,7 @@ pub struct RuleErrorInfo {
     /// Type of rule which failed.
     rule: &'static str,
     /// DB object which failed the rule.
-    object: String,
+    object: ObjectRef,
     /// Error message describing the failure.
     message: String,
     /// What should be done to fix the failure.
@@ -82,7 +87,7 @@ impl RuleFailureInformation for RuleErrorInfo {
         self.rule
     }
 
-    fn object(&self) -> &str {
+    fn object(&self) -> &ObjectRef {
         &self.object
     }
 
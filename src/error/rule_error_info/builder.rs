@@ -1,6 +1,6 @@
 //! Submodule providing the builder for `RuleErrorInfo`.
 
-use crate::error::RuleErrorInfo;
+use crate::error::{ObjectRef, RuleErrorInfo};
 
 #[derive(Default)]
 /// Builder for `RuleErrorInfo`.
@@ -8,12 +8,17 @@ use crate::error::RuleErrorInfo;
 /// # Example
 ///
 /// ```rust
-/// use sql_rules::error::RuleErrorInfo;
+/// use sql_rules::error::{ObjectRef, RuleErrorInfo};
+///
+/// let table = |name: &str| ObjectRef::Table {
+///     schema: None,
+///     table: name.to_string(),
+/// };
 ///
 /// // Successful build
 /// let error_info: RuleErrorInfo = RuleErrorInfo::builder()
 ///     .rule("TestRule").unwrap()
-///     .object("test_table".to_string()).unwrap()
+///     .object(table("test_table")).unwrap()
 ///     .message("Test message".to_string()).unwrap()
 ///     .resolution("Fix the issue".to_string()).unwrap()
 ///     .try_into()
@@ -21,13 +26,13 @@ use crate::error::RuleErrorInfo;
 ///
 /// // Error cases
 /// assert!(RuleErrorInfo::builder().rule("").is_err()); // Empty rule
-/// assert!(RuleErrorInfo::builder().object("".to_string()).is_err()); // Empty object
+/// assert!(RuleErrorInfo::builder().object(table("")).is_err()); // Empty object
 /// assert!(RuleErrorInfo::builder().message("".to_string()).is_err()); // Empty message
 /// assert!(RuleErrorInfo::builder().resolution("".to_string()).is_err()); // Empty resolution
 /// ```
 pub struct RuleErrorInfoBuilder {
     rule: Option<&'static str>,
-    object: Option<String>,
+    object: Option<ObjectRef>,
     message: Option<String>,
     resolution: Option<String>,
 }
@@ -43,8 +48,8 @@ impl RuleErrorInfoBuilder {
     }
 
     /// Set the `object` attribute.
-    pub fn object(mut self, object: String) -> Result<Self, RuleErrorInfoBuilderError> {
-        if object.trim().is_empty() {
+    pub fn object(mut self, object: ObjectRef) -> Result<Self, RuleErrorInfoBuilderError> {
+        if object.table_name().trim().is_empty() {
             return Err(RuleErrorInfoBuilderError::EmptyObject);
         }
         self.object = Some(object);
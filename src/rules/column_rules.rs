@@ -1,18 +1,36 @@
 //! Submodule providing constraint structs that can be applied to columns.
 
-mod lowercase_column_name;
-pub use lowercase_column_name::LowercaseColumnName;
-mod no_rust_keyword_column_name;
-pub use no_rust_keyword_column_name::NoRustKeywordColumnName;
 mod non_composite_primary_key_named_id;
 pub use non_composite_primary_key_named_id::NonCompositePrimaryKeyNamedId;
 mod no_surrogate_primary_key_in_extension;
 pub use no_surrogate_primary_key_in_extension::NoSurrogatePrimaryKeyInExtension;
-mod snake_case_column_name;
-pub use snake_case_column_name::SnakeCaseColumnName;
 mod singular_column_name;
 pub use singular_column_name::SingularColumnName;
 mod textual_column_rule;
 pub use textual_column_rule::TextualColumnRule;
 mod past_time_column_rule;
 pub use past_time_column_rule::PastTimeColumnRule;
+mod id_column_is_foreign_key;
+pub use id_column_is_foreign_key::IdColumnIsForeignKey;
+mod timestamp_with_time_zone;
+pub use timestamp_with_time_zone::TimestampWithTimeZone;
+mod no_floating_point_money;
+pub use no_floating_point_money::NoFloatingPointMoney;
+mod no_fixed_length_char;
+pub use no_fixed_length_char::NoFixedLengthChar;
+mod prefer_jsonb;
+pub use prefer_jsonb::PreferJsonb;
+mod prefer_identity_column;
+pub use prefer_identity_column::PreferIdentityColumn;
+mod no_money_type;
+pub use no_money_type::NoMoneyType;
+mod big_int_primary_key;
+pub use big_int_primary_key::BigIntPrimaryKey;
+mod new_not_null_column_has_default;
+pub use new_not_null_column_has_default::NewNotNullColumnHasDefault;
+mod no_nullable_boolean;
+pub use no_nullable_boolean::NoNullableBoolean;
+mod boolean_column_prefix;
+pub use boolean_column_prefix::BooleanColumnPrefix;
+mod numeric_column_rule;
+pub use numeric_column_rule::NumericColumnRule;
@@ -0,0 +1,125 @@
+//! Submodule providing the `BigIntPrimaryKey` constraint, which enforces that
+//! integer primary keys are 64-bit integers.
+
+use sql_traits::traits::{ColumnLike, DatabaseLike, TableLike};
+
+use crate::{
+    error::{ObjectRef, RuleErrorInfo},
+    rules::data_types::{NARROW_INTEGER_DATA_TYPES, base_type},
+    traits::{ColumnRule, Constrainer, GenericConstrainer},
+};
+
+/// Data types of the integer columns narrower than 64 bits, lowercased.
+/// Struct defining a constraint that enforces that non-composite integer
+/// primary keys are declared as `BIGINT`, since exhausting a 32-bit key space
+/// requires a costly migration of the table and of every referencing column.
+///
+/// Small lookup tables, whose number of rows is bounded, can be exempted.
+///
+/// # Example
+///
+/// Here follows an example of validating an invalid SQL statement with the
+/// `BigIntPrimaryKey` constraint.
+///
+/// ```rust
+/// use sql_rules::prelude::*;
+/// use sqlparser::dialect::GenericDialect;
+///
+/// let constrainer: GenericConstrainer<ParserDB> = BigIntPrimaryKey::default().into();
+///
+/// let invalid_schema =
+///     ParserDB::parse::<GenericDialect>("CREATE TABLE users (id INT PRIMARY KEY);").unwrap();
+/// assert!(constrainer.validate_schema(&invalid_schema).is_err());
+///
+/// let valid_schema =
+///     ParserDB::parse::<GenericDialect>("CREATE TABLE users (id BIGINT PRIMARY KEY);").unwrap();
+/// assert!(constrainer.validate_schema(&valid_schema).is_ok());
+///
+/// // Lookup tables can be exempted
+/// let constrainer: GenericConstrainer<ParserDB> =
+///     BigIntPrimaryKey::default().with_exempted_table("colors").into();
+/// let valid_schema =
+///     ParserDB::parse::<GenericDialect>("CREATE TABLE colors (id SMALLINT PRIMARY KEY);").unwrap();
+/// assert!(constrainer.validate_schema(&valid_schema).is_ok());
+/// ```
+pub struct BigIntPrimaryKey<DB> {
+    exempted_tables: Vec<String>,
+    _phantom: std::marker::PhantomData<DB>,
+}
+
+impl<DB> BigIntPrimaryKey<DB> {
+    /// Adds the name of a table whose primary key is exempted from the rule.
+    #[must_use]
+    pub fn with_exempted_table(mut self, table_name: impl Into<String>) -> Self {
+        self.exempted_tables.push(table_name.into());
+        self
+    }
+}
+
+impl<DB> Default for BigIntPrimaryKey<DB> {
+    fn default() -> Self {
+        Self {
+            exempted_tables: Vec::new(),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<DB: DatabaseLike + 'static> From<BigIntPrimaryKey<DB>> for GenericConstrainer<DB> {
+    fn from(constraint: BigIntPrimaryKey<DB>) -> Self {
+        let mut constrainer = GenericConstrainer::default();
+        constrainer.register_column_rule(Box::new(constraint));
+        constrainer
+    }
+}
+
+impl<DB: DatabaseLike> ColumnRule for BigIntPrimaryKey<DB> {
+    type Database = DB;
+
+    fn validate_column(
+        &self,
+        database: &Self::Database,
+        column: &<Self::Database as DatabaseLike>::Column,
+    ) -> Result<(), crate::error::Error<DB>> {
+        if !column.is_primary_key(database) {
+            return Ok(());
+        }
+        let table = column.table(database);
+        let table_name = table.table_name();
+        if table.primary_key_columns(database).count() != 1
+            || self
+                .exempted_tables
+                .iter()
+                .any(|exempted| exempted.eq_ignore_ascii_case(table_name))
+        {
+            return Ok(());
+        }
+
+        let data_type = column.normalized_data_type(database).to_lowercase();
+        if !NARROW_INTEGER_DATA_TYPES.contains(&base_type(&data_type)) {
+            return Ok(());
+        }
+
+        let column_name = column.column_name();
+        let error: RuleErrorInfo = RuleErrorInfo::builder()
+            .rule("BigIntPrimaryKey")
+            .unwrap()
+            .object(ObjectRef::column(database, column))
+            .unwrap()
+            .message(format!(
+                "Primary key '{table_name}.{column_name}' is a {}, whose key space may be exhausted.",
+                data_type.to_uppercase()
+            ))
+            .unwrap()
+            .resolution(format!(
+                "Declare primary key '{table_name}.{column_name}' (and the columns referencing it) as `BIGINT`, or exempt table '{table_name}' if its number of rows is bounded."
+            ))
+            .unwrap()
+            .try_into()
+            .unwrap();
+        Err(crate::error::Error::Column(
+            Box::new(column.clone()),
+            error.into(),
+        ))
+    }
+}
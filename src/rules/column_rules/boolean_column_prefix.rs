@@ -0,0 +1,179 @@
+//! Submodule providing the `BooleanColumnPrefix` constraint, which enforces
+//! that boolean columns, and only boolean columns, are named as predicates.
+
+use sql_traits::traits::{ColumnLike, DatabaseLike, TableLike};
+
+use crate::{
+    error::{ObjectRef, RuleErrorInfo},
+    traits::{ColumnRule, Constrainer, GenericConstrainer},
+};
+
+/// Struct defining a constraint that enforces that the names of boolean
+/// columns start with one of the configured prefixes (by default `is_`,
+/// `has_`, `can_` and `should_`), and conversely that the names of columns
+/// which are not boolean do not start with any of them. When no prefix is
+/// configured, there is nothing to enforce and every column is accepted.
+///
+/// # Example
+///
+/// Here follows an example of validating an invalid SQL statement with the
+/// `BooleanColumnPrefix` constraint.
+///
+/// ```rust
+/// use sql_rules::prelude::*;
+/// use sqlparser::dialect::GenericDialect;
+///
+/// let constrainer: GenericConstrainer<ParserDB> = BooleanColumnPrefix::default().into();
+///
+/// // Invalid: boolean column without a prefix
+/// let invalid_schema =
+///     ParserDB::parse::<GenericDialect>("CREATE TABLE users (id INT PRIMARY KEY, active BOOLEAN);")
+///         .unwrap();
+/// assert!(constrainer.validate_schema(&invalid_schema).is_err());
+///
+/// // Invalid: textual column with a boolean prefix
+/// let invalid_schema =
+///     ParserDB::parse::<GenericDialect>("CREATE TABLE users (id INT PRIMARY KEY, is_admin TEXT);")
+///         .unwrap();
+/// assert!(constrainer.validate_schema(&invalid_schema).is_err());
+///
+/// let valid_schema = ParserDB::parse::<GenericDialect>(
+///     "CREATE TABLE users (id INT PRIMARY KEY, is_active BOOLEAN, has_avatar BOOLEAN, name TEXT);",
+/// )
+/// .unwrap();
+/// assert!(constrainer.validate_schema(&valid_schema).is_ok());
+///
+/// // Custom prefixes
+/// let constrainer: GenericConstrainer<ParserDB> =
+///     BooleanColumnPrefix::default().with_prefix("was_").into();
+/// let valid_schema =
+///     ParserDB::parse::<GenericDialect>("CREATE TABLE users (id INT PRIMARY KEY, was_notified BOOLEAN);")
+///         .unwrap();
+/// assert!(constrainer.validate_schema(&valid_schema).is_ok());
+///
+/// // Replaced prefixes are the ones suggested
+/// let constrainer: GenericConstrainer<ParserDB> =
+///     BooleanColumnPrefix::default().with_prefixes(["flag_"]).into();
+/// let invalid_schema =
+///     ParserDB::parse::<GenericDialect>("CREATE TABLE users (id INT PRIMARY KEY, active BOOLEAN);")
+///         .unwrap();
+/// let error = constrainer.validate_schema(&invalid_schema).unwrap_err();
+/// assert!(error.to_string().contains("'flag_active'"));
+/// assert!(!error.to_string().contains("'is_active'"));
+///
+/// // Without prefixes, every column is accepted
+/// let constrainer: GenericConstrainer<ParserDB> =
+///     BooleanColumnPrefix::default().with_prefixes(Vec::<String>::new()).into();
+/// assert!(constrainer.validate_schema(&invalid_schema).is_ok());
+/// ```
+pub struct BooleanColumnPrefix<DB> {
+    prefixes: Vec<String>,
+    _phantom: std::marker::PhantomData<DB>,
+}
+
+impl<DB> BooleanColumnPrefix<DB> {
+    /// Replaces the prefixes of boolean column names.
+    #[must_use]
+    pub fn with_prefixes<S: Into<String>>(mut self, prefixes: impl IntoIterator<Item = S>) -> Self {
+        self.prefixes = prefixes.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Adds a prefix of boolean column names.
+    #[must_use]
+    pub fn with_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefixes.push(prefix.into());
+        self
+    }
+
+    /// Returns the prefix the provided column name starts with, if any.
+    fn prefix_of(&self, column_name: &str) -> Option<&str> {
+        let column_name = column_name.to_lowercase();
+        self.prefixes
+            .iter()
+            .find(|prefix| column_name.starts_with(&prefix.to_lowercase()))
+            .map(String::as_str)
+    }
+}
+
+impl<DB> Default for BooleanColumnPrefix<DB> {
+    fn default() -> Self {
+        Self {
+            prefixes: ["is_", "has_", "can_", "should_"]
+                .map(String::from)
+                .to_vec(),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<DB: DatabaseLike + 'static> From<BooleanColumnPrefix<DB>> for GenericConstrainer<DB> {
+    fn from(constraint: BooleanColumnPrefix<DB>) -> Self {
+        let mut constrainer = GenericConstrainer::default();
+        constrainer.register_column_rule(Box::new(constraint));
+        constrainer
+    }
+}
+
+impl<DB: DatabaseLike> ColumnRule for BooleanColumnPrefix<DB> {
+    type Database = DB;
+
+    fn validate_column(
+        &self,
+        database: &Self::Database,
+        column: &<Self::Database as DatabaseLike>::Column,
+    ) -> Result<(), crate::error::Error<DB>> {
+        if self.prefixes.is_empty() {
+            return Ok(());
+        }
+        let column_name = column.column_name();
+        let table_name = column.table(database).table_name();
+        let prefixes = self.prefixes.join("`, `");
+        let (message, resolution) = match (column.is_boolean(database), self.prefix_of(column_name))
+        {
+            (true, None) => {
+                let suggestions = self
+                    .prefixes
+                    .iter()
+                    .take(2)
+                    .map(|prefix| format!("'{prefix}{column_name}'"))
+                    .collect::<Vec<_>>()
+                    .join(" or ");
+                (
+                    format!(
+                        "Boolean column '{table_name}.{column_name}' does not start with any of the prefixes `{prefixes}`."
+                    ),
+                    format!(
+                        "Rename column '{table_name}.{column_name}' as a predicate, e.g. {suggestions}."
+                    ),
+                )
+            }
+            (false, Some(prefix)) => (
+                format!(
+                    "Column '{table_name}.{column_name}' starts with the boolean prefix `{prefix}` but is of type {}.",
+                    column.normalized_data_type(database)
+                ),
+                format!(
+                    "Rename column '{table_name}.{column_name}' without the `{prefix}` prefix, or declare it as BOOLEAN if it holds a truth value."
+                ),
+            ),
+            _ => return Ok(()),
+        };
+
+        let error: RuleErrorInfo = RuleErrorInfo::builder()
+            .rule("BooleanColumnPrefix")
+            .unwrap()
+            .object(ObjectRef::column(database, column))
+            .unwrap()
+            .message(message)
+            .unwrap()
+            .resolution(resolution)
+            .unwrap()
+            .try_into()
+            .unwrap();
+        Err(crate::error::Error::Column(
+            Box::new(column.clone()),
+            error.into(),
+        ))
+    }
+}
@@ -0,0 +1,239 @@
+//! Submodule providing the `IdColumnIsForeignKey` constraint, which enforces
+//! that identifier-like columns are declared as foreign keys.
+
+use sql_traits::traits::{ColumnLike, DatabaseLike, ForeignKeyLike, TableLike};
+
+use crate::{
+    error::{ObjectRef, RuleErrorInfo},
+    rules::{
+        InflectionDictionary,
+        data_types::{base_type, is_integer},
+    },
+    traits::{ColumnRule, Constrainer, GenericConstrainer},
+};
+
+/// Data types of the columns which may hold identifiers, lowercased.
+/// Struct defining a constraint that enforces that integer or UUID columns
+/// whose name ends with `_id` (configurable) are either the host column of a
+/// foreign key or part of the primary key of their table.
+///
+/// When the column is not a foreign key, the error suggests the probable
+/// referenced table, by matching the prefix of the column name, pluralized
+/// with an [`InflectionDictionary`], against the tables of the schema.
+///
+/// # Example
+///
+/// Here follows an example of validating an invalid SQL statement with the
+/// `IdColumnIsForeignKey` constraint.
+///
+/// ```rust
+/// use sql_rules::prelude::*;
+/// use sqlparser::dialect::GenericDialect;
+///
+/// let constrainer: GenericConstrainer<ParserDB> = IdColumnIsForeignKey::default().into();
+///
+/// let invalid_schema = ParserDB::parse::<GenericDialect>(
+///     "
+/// CREATE TABLE users (id INT PRIMARY KEY);
+/// CREATE TABLE posts (id INT PRIMARY KEY, user_id INT);
+/// ",
+/// )
+/// .unwrap();
+/// assert!(constrainer.validate_schema(&invalid_schema).is_err());
+///
+/// let valid_schema = ParserDB::parse::<GenericDialect>(
+///     "
+/// CREATE TABLE users (id INT PRIMARY KEY);
+/// CREATE TABLE posts (id INT PRIMARY KEY, user_id INT REFERENCES users (id));
+/// ",
+/// )
+/// .unwrap();
+/// assert!(constrainer.validate_schema(&valid_schema).is_ok());
+///
+/// // Scientific plurals are found through the dictionary
+/// let invalid_schema = ParserDB::parse::<GenericDialect>(
+///     "
+/// CREATE TABLE taxa (id INT PRIMARY KEY);
+/// CREATE TABLE spectra (id INT PRIMARY KEY);
+/// CREATE TABLE observations (id INT PRIMARY KEY, taxon_id INT, spectrum_id INT REFERENCES spectra (id));
+/// ",
+/// )
+/// .unwrap();
+/// let error = constrainer.validate_schema(&invalid_schema).unwrap_err();
+/// assert!(error.to_string().contains("REFERENCES taxa (id)"));
+///
+/// // The suffix is matched ignoring case
+/// let invalid_schema = ParserDB::parse::<GenericDialect>(
+///     "
+/// CREATE TABLE users (id INT PRIMARY KEY);
+/// CREATE TABLE posts (id INT PRIMARY KEY, UserID INT);
+/// ",
+/// )
+/// .unwrap();
+/// assert!(constrainer.validate_schema(&invalid_schema).is_err());
+///
+/// // Textual identifiers and exempted columns are not subject to the rule
+/// let constrainer: GenericConstrainer<ParserDB> =
+///     IdColumnIsForeignKey::default().with_exemption("external_id").into();
+/// let valid_schema = ParserDB::parse::<GenericDialect>(
+///     "CREATE TABLE posts (id INT PRIMARY KEY, orcid_id TEXT, external_id BIGINT);",
+/// )
+/// .unwrap();
+/// assert!(constrainer.validate_schema(&valid_schema).is_ok());
+/// ```
+pub struct IdColumnIsForeignKey<DB> {
+    suffix: String,
+    exemptions: Vec<String>,
+    dictionary: InflectionDictionary,
+    _phantom: std::marker::PhantomData<DB>,
+}
+
+impl<DB> IdColumnIsForeignKey<DB> {
+    /// Sets the suffix identifying the columns subject to the rule, by
+    /// default `_id`.
+    #[must_use]
+    pub fn with_suffix(mut self, suffix: impl Into<String>) -> Self {
+        self.suffix = suffix.into();
+        self
+    }
+
+    /// Adds the name of a column exempted from the rule, such as an
+    /// identifier in an external system.
+    #[must_use]
+    pub fn with_exemption(mut self, column_name: impl Into<String>) -> Self {
+        self.exemptions.push(column_name.into());
+        self
+    }
+
+    /// Sets the dictionary of the exceptions to the inflection rules.
+    #[must_use]
+    pub fn with_dictionary(mut self, dictionary: InflectionDictionary) -> Self {
+        self.dictionary = dictionary;
+        self
+    }
+}
+
+impl<DB> IdColumnIsForeignKey<DB> {
+    /// Returns the provided column name without the suffix of the rule,
+    /// compared ignoring ASCII case, if it ends with it.
+    fn strip_suffix<'a>(&self, column_name: &'a str) -> Option<&'a str> {
+        let (prefix, suffix) =
+            column_name.split_at_checked(column_name.len().checked_sub(self.suffix.len())?)?;
+        suffix.eq_ignore_ascii_case(&self.suffix).then_some(prefix)
+    }
+
+    /// Returns the names of the tables which a column with the provided name
+    /// prefix probably references, from the most to the least specific.
+    fn candidate_table_names(&self, prefix: &str) -> Vec<String> {
+        let mut candidates = vec![self.dictionary.pluralize_name(prefix), prefix.to_string()];
+        let last_segment = self.dictionary.last_segment(prefix);
+        if last_segment != prefix {
+            candidates.push(self.dictionary.pluralize(last_segment));
+            candidates.push(last_segment.to_string());
+        }
+        candidates
+    }
+}
+
+impl<DB> Default for IdColumnIsForeignKey<DB> {
+    fn default() -> Self {
+        Self {
+            suffix: "_id".to_string(),
+            exemptions: Vec::new(),
+            dictionary: InflectionDictionary::default(),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<DB: DatabaseLike + 'static> From<IdColumnIsForeignKey<DB>> for GenericConstrainer<DB> {
+    fn from(constraint: IdColumnIsForeignKey<DB>) -> Self {
+        let mut constrainer = GenericConstrainer::default();
+        constrainer.register_column_rule(Box::new(constraint));
+        constrainer
+    }
+}
+
+impl<DB: DatabaseLike> ColumnRule for IdColumnIsForeignKey<DB> {
+    type Database = DB;
+
+    fn validate_column(
+        &self,
+        database: &Self::Database,
+        column: &<Self::Database as DatabaseLike>::Column,
+    ) -> Result<(), crate::error::Error<DB>> {
+        let column_name = column.column_name();
+        let Some(prefix) = self.strip_suffix(column_name) else {
+            return Ok(());
+        };
+        if prefix.is_empty()
+            || self
+                .exemptions
+                .iter()
+                .any(|exemption| exemption.eq_ignore_ascii_case(column_name))
+        {
+            return Ok(());
+        }
+
+        let data_type = column.normalized_data_type(database).to_lowercase();
+        if !(is_integer(&data_type) || base_type(&data_type) == "uuid")
+            || column.is_primary_key(database)
+        {
+            return Ok(());
+        }
+
+        let table = column.table(database);
+        if table.foreign_keys(database).any(|fk| {
+            fk.host_columns(database)
+                .any(|host_column| host_column.column_name() == column_name)
+        }) {
+            return Ok(());
+        }
+
+        let table_name = table.table_name();
+        let candidate = self
+            .candidate_table_names(prefix)
+            .into_iter()
+            .find_map(|name| {
+                database
+                    .tables()
+                    .find(|candidate| candidate.table_name().eq_ignore_ascii_case(&name))
+            });
+        let resolution = match candidate {
+            Some(referenced_table) => {
+                let referenced_columns = referenced_table
+                    .primary_key_columns(database)
+                    .map(ColumnLike::column_name)
+                    .collect::<Vec<_>>();
+                let referenced_column = match referenced_columns.as_slice() {
+                    [referenced_column] => referenced_column,
+                    _ => "id",
+                };
+                format!(
+                    "Declare the foreign key, probably `FOREIGN KEY ({column_name}) REFERENCES {} ({referenced_column})`, or rename the column if it does not reference another table.",
+                    referenced_table.table_name()
+                )
+            }
+            None => format!(
+                "Declare a foreign key on '{table_name}.{column_name}' referencing the table it identifies, or rename the column if it does not reference another table."
+            ),
+        };
+        let error: RuleErrorInfo = RuleErrorInfo::builder()
+            .rule("IdColumnIsForeignKey")
+            .unwrap()
+            .object(ObjectRef::column(database, column))
+            .unwrap()
+            .message(format!(
+                "Column '{table_name}.{column_name}' looks like a reference to another table, but it is neither a foreign key nor part of the primary key."
+            ))
+            .unwrap()
+            .resolution(resolution)
+            .unwrap()
+            .try_into()
+            .unwrap();
+        Err(crate::error::Error::Column(
+            Box::new(column.clone()),
+            error.into(),
+        ))
+    }
+}
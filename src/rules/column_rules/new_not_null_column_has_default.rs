@@ -0,0 +1,117 @@
+//! Submodule providing the `NewNotNullColumnHasDefault` constraint, which
+//! enforces that `NOT NULL` columns added to existing tables have a default.
+
+use sql_traits::traits::{ColumnLike, DatabaseLike, TableLike};
+
+use crate::{
+    error::{ObjectRef, RuleErrorInfo},
+    traits::{ColumnRule, Constrainer, GenericConstrainer},
+};
+
+/// Struct defining a constraint that compares the schema with a baseline
+/// (e.g. the schema currently deployed) and enforces that `NOT NULL` columns
+/// added to a table which already exists in the baseline have a default
+/// value or are generated, since otherwise the migration adding them fails as
+/// soon as the table contains rows.
+///
+/// Columns of tables which do not exist in the baseline are not subject to
+/// the rule.
+///
+/// # Example
+///
+/// Here follows an example of validating an invalid SQL statement with the
+/// `NewNotNullColumnHasDefault` constraint.
+///
+/// ```rust
+/// use sql_rules::prelude::*;
+/// use sqlparser::dialect::GenericDialect;
+///
+/// let baseline =
+///     ParserDB::parse::<GenericDialect>("CREATE TABLE users (id INT PRIMARY KEY);").unwrap();
+/// let constrainer: GenericConstrainer<ParserDB> = NewNotNullColumnHasDefault::new(baseline).into();
+///
+/// let invalid_schema = ParserDB::parse::<GenericDialect>(
+///     "CREATE TABLE users (id INT PRIMARY KEY, name TEXT NOT NULL);",
+/// )
+/// .unwrap();
+/// assert!(constrainer.validate_schema(&invalid_schema).is_err());
+///
+/// let valid_schema = ParserDB::parse::<GenericDialect>(
+///     "
+/// CREATE TABLE users (id INT PRIMARY KEY, name TEXT NOT NULL DEFAULT 'anonymous', bio TEXT);
+/// CREATE TABLE posts (id INT PRIMARY KEY, title TEXT NOT NULL);
+/// ",
+/// )
+/// .unwrap();
+/// assert!(constrainer.validate_schema(&valid_schema).is_ok());
+/// ```
+pub struct NewNotNullColumnHasDefault<DB> {
+    baseline: DB,
+}
+
+impl<DB> NewNotNullColumnHasDefault<DB> {
+    /// Creates a new `NewNotNullColumnHasDefault` constraint comparing the
+    /// validated schemas with the provided baseline schema.
+    #[must_use]
+    pub fn new(baseline: DB) -> Self {
+        Self { baseline }
+    }
+}
+
+impl<DB: DatabaseLike + 'static> From<NewNotNullColumnHasDefault<DB>> for GenericConstrainer<DB> {
+    fn from(constraint: NewNotNullColumnHasDefault<DB>) -> Self {
+        let mut constrainer = GenericConstrainer::default();
+        constrainer.register_column_rule(Box::new(constraint));
+        constrainer
+    }
+}
+
+impl<DB: DatabaseLike> ColumnRule for NewNotNullColumnHasDefault<DB> {
+    type Database = DB;
+
+    fn validate_column(
+        &self,
+        database: &Self::Database,
+        column: &<Self::Database as DatabaseLike>::Column,
+    ) -> Result<(), crate::error::Error<DB>> {
+        if column.is_nullable(database) || column.has_default() || column.is_generated() {
+            return Ok(());
+        }
+
+        let table = column.table(database);
+        let table_name = table.table_name();
+        let column_name = column.column_name();
+        let Some(baseline_table) = self.baseline.tables().find(|baseline_table| {
+            baseline_table.table_name() == table_name
+                && baseline_table.table_schema() == table.table_schema()
+        }) else {
+            return Ok(());
+        };
+        if baseline_table
+            .columns(&self.baseline)
+            .any(|baseline_column| baseline_column.column_name() == column_name)
+        {
+            return Ok(());
+        }
+
+        let error: RuleErrorInfo = RuleErrorInfo::builder()
+            .rule("NewNotNullColumnHasDefault")
+            .unwrap()
+            .object(ObjectRef::column(database, column))
+            .unwrap()
+            .message(format!(
+                "Column '{table_name}.{column_name}' is added to an existing table as NOT NULL without a default value, so adding it fails if the table has rows."
+            ))
+            .unwrap()
+            .resolution(format!(
+                "Add a DEFAULT to column '{table_name}.{column_name}', or add it as nullable, backfill it, and only then make it NOT NULL."
+            ))
+            .unwrap()
+            .try_into()
+            .unwrap();
+        Err(crate::error::Error::Column(
+            Box::new(column.clone()),
+            error.into(),
+        ))
+    }
+}
@@ -0,0 +1,126 @@
+//! Submodule providing the `NoFixedLengthChar` constraint, which enforces
+//! that textual columns are not blank-padded `CHAR(n)` columns.
+
+use sql_traits::traits::{ColumnLike, DatabaseLike, TableLike};
+
+use crate::{
+    error::{ObjectRef, RuleErrorInfo},
+    rules::data_types::{FIXED_LENGTH_CHAR_DATA_TYPES, base_type},
+    traits::{ColumnRule, Constrainer, GenericConstrainer},
+};
+
+/// Struct defining a constraint that enforces that columns are not declared
+/// as `CHAR(n)` (or `CHARACTER(n)`, `NCHAR(n)`), whose values are padded with
+/// blanks up to the declared length, leading to surprising comparisons.
+///
+/// Columns which genuinely hold fixed-length codes, such as ISO country
+/// codes, can be exempted.
+///
+/// # Example
+///
+/// Here follows an example of validating an invalid SQL statement with the
+/// `NoFixedLengthChar` constraint.
+///
+/// ```rust
+/// use sql_rules::prelude::*;
+/// use sqlparser::dialect::GenericDialect;
+///
+/// let constrainer: GenericConstrainer<ParserDB> = NoFixedLengthChar::default().into();
+///
+/// let invalid_schema =
+///     ParserDB::parse::<GenericDialect>("CREATE TABLE users (id INT PRIMARY KEY, name CHAR(100));")
+///         .unwrap();
+/// assert!(constrainer.validate_schema(&invalid_schema).is_err());
+///
+/// let valid_schema =
+///     ParserDB::parse::<GenericDialect>("CREATE TABLE users (id INT PRIMARY KEY, name VARCHAR(100));")
+///         .unwrap();
+/// assert!(constrainer.validate_schema(&valid_schema).is_ok());
+///
+/// // Fixed-length codes can be exempted
+/// let constrainer: GenericConstrainer<ParserDB> =
+///     NoFixedLengthChar::default().with_exemption("country_code").into();
+/// let valid_schema = ParserDB::parse::<GenericDialect>(
+///     "CREATE TABLE users (id INT PRIMARY KEY, country_code CHAR(2));",
+/// )
+/// .unwrap();
+/// assert!(constrainer.validate_schema(&valid_schema).is_ok());
+/// ```
+pub struct NoFixedLengthChar<DB> {
+    exemptions: Vec<String>,
+    _phantom: std::marker::PhantomData<DB>,
+}
+
+impl<DB> NoFixedLengthChar<DB> {
+    /// Adds the name of a column exempted from the rule.
+    #[must_use]
+    pub fn with_exemption(mut self, column_name: impl Into<String>) -> Self {
+        self.exemptions.push(column_name.into());
+        self
+    }
+}
+
+impl<DB> Default for NoFixedLengthChar<DB> {
+    fn default() -> Self {
+        Self {
+            exemptions: Vec::new(),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<DB: DatabaseLike + 'static> From<NoFixedLengthChar<DB>> for GenericConstrainer<DB> {
+    fn from(constraint: NoFixedLengthChar<DB>) -> Self {
+        let mut constrainer = GenericConstrainer::default();
+        constrainer.register_column_rule(Box::new(constraint));
+        constrainer
+    }
+}
+
+impl<DB: DatabaseLike> ColumnRule for NoFixedLengthChar<DB> {
+    type Database = DB;
+
+    fn validate_column(
+        &self,
+        database: &Self::Database,
+        column: &<Self::Database as DatabaseLike>::Column,
+    ) -> Result<(), crate::error::Error<DB>> {
+        let column_name = column.column_name();
+        if self
+            .exemptions
+            .iter()
+            .any(|exemption| exemption.eq_ignore_ascii_case(column_name))
+        {
+            return Ok(());
+        }
+
+        let data_type = column.normalized_data_type(database).to_lowercase();
+        if !FIXED_LENGTH_CHAR_DATA_TYPES.contains(&base_type(&data_type))
+            || data_type.contains("varying")
+        {
+            return Ok(());
+        }
+
+        let table_name = column.table(database).table_name();
+        let error: RuleErrorInfo = RuleErrorInfo::builder()
+            .rule("NoFixedLengthChar")
+            .unwrap()
+            .object(ObjectRef::column(database, column))
+            .unwrap()
+            .message(format!(
+                "Column '{table_name}.{column_name}' is a fixed-length {}, whose values are padded with blanks.",
+                data_type.to_uppercase()
+            ))
+            .unwrap()
+            .resolution(format!(
+                "Declare column '{table_name}.{column_name}' as `VARCHAR(n)` or `TEXT` with a length check constraint, or exempt it if it holds fixed-length codes."
+            ))
+            .unwrap()
+            .try_into()
+            .unwrap();
+        Err(crate::error::Error::Column(
+            Box::new(column.clone()),
+            error.into(),
+        ))
+    }
+}
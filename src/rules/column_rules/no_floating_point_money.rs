@@ -0,0 +1,156 @@
+//! Submodule providing the `NoFloatingPointMoney` constraint, which enforces
+//! that monetary columns are not floating point numbers.
+
+use sql_traits::traits::{ColumnLike, DatabaseLike, TableLike};
+
+use crate::{
+    error::{ObjectRef, RuleErrorInfo},
+    rules::data_types::{FLOATING_POINT_DATA_TYPES, base_type},
+    traits::{ColumnRule, Constrainer, GenericConstrainer},
+};
+
+/// Struct defining a constraint that enforces that columns with a money-like
+/// name are not declared as floating point numbers (`FLOAT`, `REAL`,
+/// `DOUBLE PRECISION`), which cannot represent most decimal amounts exactly.
+///
+/// A column name is money-like when one of its `snake_case` words is one of
+/// the configured words, by default `price`, `amount`, `cost`, `fee` and
+/// `salary`. Words such as `total` or `balance` are not included, as they
+/// name counts and quantities as often as amounts of money.
+///
+/// # Example
+///
+/// Here follows an example of validating an invalid SQL statement with the
+/// `NoFloatingPointMoney` constraint.
+///
+/// ```rust
+/// use sql_rules::prelude::*;
+/// use sqlparser::dialect::GenericDialect;
+///
+/// let constrainer: GenericConstrainer<ParserDB> = NoFloatingPointMoney::default().into();
+///
+/// let invalid_schema = ParserDB::parse::<GenericDialect>(
+///     "CREATE TABLE orders (id INT PRIMARY KEY, unit_price DOUBLE PRECISION);",
+/// )
+/// .unwrap();
+/// assert!(constrainer.validate_schema(&invalid_schema).is_err());
+///
+/// let valid_schema = ParserDB::parse::<GenericDialect>(
+///     "CREATE TABLE orders (id INT PRIMARY KEY, unit_price NUMERIC(12, 2), weight REAL);",
+/// )
+/// .unwrap();
+/// assert!(constrainer.validate_schema(&valid_schema).is_ok());
+///
+/// // Additional money-like words can be configured
+/// let constrainer: GenericConstrainer<ParserDB> =
+///     NoFloatingPointMoney::default().with_money_word("wage").into();
+/// let invalid_schema =
+///     ParserDB::parse::<GenericDialect>("CREATE TABLE jobs (id INT PRIMARY KEY, hourly_wage FLOAT);")
+///         .unwrap();
+/// assert!(constrainer.validate_schema(&invalid_schema).is_err());
+///
+/// // Or replace the default ones altogether
+/// let constrainer: GenericConstrainer<ParserDB> =
+///     NoFloatingPointMoney::default().with_money_words(["balance"]).into();
+/// let valid_schema = ParserDB::parse::<GenericDialect>(
+///     "CREATE TABLE orders (id INT PRIMARY KEY, unit_price REAL, total_weight REAL);",
+/// )
+/// .unwrap();
+/// assert!(constrainer.validate_schema(&valid_schema).is_ok());
+/// let invalid_schema = ParserDB::parse::<GenericDialect>(
+///     "CREATE TABLE accounts (id INT PRIMARY KEY, balance DOUBLE PRECISION);",
+/// )
+/// .unwrap();
+/// assert!(constrainer.validate_schema(&invalid_schema).is_err());
+/// ```
+pub struct NoFloatingPointMoney<DB> {
+    money_words: Vec<String>,
+    _phantom: std::marker::PhantomData<DB>,
+}
+
+impl<DB> NoFloatingPointMoney<DB> {
+    /// Adds a word identifying money-like column names.
+    #[must_use]
+    pub fn with_money_word(mut self, word: impl Into<String>) -> Self {
+        self.money_words.push(word.into());
+        self
+    }
+
+    /// Replaces the words identifying money-like column names.
+    #[must_use]
+    pub fn with_money_words<S: Into<String>>(mut self, words: impl IntoIterator<Item = S>) -> Self {
+        self.money_words = words.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Returns the money-like word in the provided column name, if any.
+    fn money_word(&self, column_name: &str) -> Option<&str> {
+        column_name.split('_').find_map(|word| {
+            self.money_words
+                .iter()
+                .find(|money_word| money_word.eq_ignore_ascii_case(word))
+                .map(String::as_str)
+        })
+    }
+}
+
+impl<DB> Default for NoFloatingPointMoney<DB> {
+    fn default() -> Self {
+        Self {
+            money_words: ["price", "amount", "cost", "fee", "salary"]
+                .map(String::from)
+                .to_vec(),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<DB: DatabaseLike + 'static> From<NoFloatingPointMoney<DB>> for GenericConstrainer<DB> {
+    fn from(constraint: NoFloatingPointMoney<DB>) -> Self {
+        let mut constrainer = GenericConstrainer::default();
+        constrainer.register_column_rule(Box::new(constraint));
+        constrainer
+    }
+}
+
+impl<DB: DatabaseLike> ColumnRule for NoFloatingPointMoney<DB> {
+    type Database = DB;
+
+    fn validate_column(
+        &self,
+        database: &Self::Database,
+        column: &<Self::Database as DatabaseLike>::Column,
+    ) -> Result<(), crate::error::Error<DB>> {
+        let column_name = column.column_name();
+        let Some(money_word) = self.money_word(column_name) else {
+            return Ok(());
+        };
+
+        let data_type = column.normalized_data_type(database).to_lowercase();
+        if !FLOATING_POINT_DATA_TYPES.contains(&base_type(&data_type)) {
+            return Ok(());
+        }
+
+        let table_name = column.table(database).table_name();
+        let error: RuleErrorInfo = RuleErrorInfo::builder()
+            .rule("NoFloatingPointMoney")
+            .unwrap()
+            .object(ObjectRef::column(database, column))
+            .unwrap()
+            .message(format!(
+                "Column '{table_name}.{column_name}' looks monetary (it contains '{money_word}') but is a floating point {}, which cannot represent most decimal amounts exactly.",
+                data_type.to_uppercase()
+            ))
+            .unwrap()
+            .resolution(format!(
+                "Declare column '{table_name}.{column_name}' as an exact `NUMERIC(precision, scale)`, or as an integer number of the smallest currency unit (e.g. cents)."
+            ))
+            .unwrap()
+            .try_into()
+            .unwrap();
+        Err(crate::error::Error::Column(
+            Box::new(column.clone()),
+            error.into(),
+        ))
+    }
+}
@@ -0,0 +1,91 @@
+//! Submodule providing the `NoMoneyType` constraint, which enforces that
+//! columns do not use the `MONEY` data type.
+
+use sql_traits::traits::{ColumnLike, DatabaseLike, TableLike};
+
+use crate::{
+    error::{ObjectRef, RuleErrorInfo},
+    traits::{ColumnRule, Constrainer, GenericConstrainer},
+};
+
+/// Struct defining a constraint that enforces that columns are not declared
+/// with the `MONEY` (or `SMALLMONEY`) data type, whose precision and output
+/// format depend on the locale settings of the database and which does not
+/// record the currency.
+///
+/// # Example
+///
+/// Here follows an example of validating an invalid SQL statement with the
+/// `NoMoneyType` constraint.
+///
+/// ```rust
+/// use sql_rules::prelude::*;
+/// use sqlparser::dialect::GenericDialect;
+///
+/// let constrainer: GenericConstrainer<ParserDB> = NoMoneyType::default().into();
+///
+/// let invalid_schema =
+///     ParserDB::parse::<GenericDialect>("CREATE TABLE orders (id INT PRIMARY KEY, total MONEY);")
+///         .unwrap();
+/// assert!(constrainer.validate_schema(&invalid_schema).is_err());
+///
+/// let valid_schema = ParserDB::parse::<GenericDialect>(
+///     "CREATE TABLE orders (id INT PRIMARY KEY, total NUMERIC(12, 2), currency CHAR(3));",
+/// )
+/// .unwrap();
+/// assert!(constrainer.validate_schema(&valid_schema).is_ok());
+/// ```
+pub struct NoMoneyType<DB>(std::marker::PhantomData<DB>);
+
+impl<DB> Default for NoMoneyType<DB> {
+    fn default() -> Self {
+        Self(std::marker::PhantomData)
+    }
+}
+
+impl<DB: DatabaseLike + 'static> From<NoMoneyType<DB>> for GenericConstrainer<DB> {
+    fn from(constraint: NoMoneyType<DB>) -> Self {
+        let mut constrainer = GenericConstrainer::default();
+        constrainer.register_column_rule(Box::new(constraint));
+        constrainer
+    }
+}
+
+impl<DB: DatabaseLike> ColumnRule for NoMoneyType<DB> {
+    type Database = DB;
+
+    fn validate_column(
+        &self,
+        database: &Self::Database,
+        column: &<Self::Database as DatabaseLike>::Column,
+    ) -> Result<(), crate::error::Error<DB>> {
+        let data_type = column.normalized_data_type(database);
+        if !data_type.eq_ignore_ascii_case("money") && !data_type.eq_ignore_ascii_case("smallmoney")
+        {
+            return Ok(());
+        }
+
+        let table_name = column.table(database).table_name();
+        let column_name = column.column_name();
+        let error: RuleErrorInfo = RuleErrorInfo::builder()
+            .rule("NoMoneyType")
+            .unwrap()
+            .object(ObjectRef::column(database, column))
+            .unwrap()
+            .message(format!(
+                "Column '{table_name}.{column_name}' is declared as `{}`, whose precision and format depend on the locale and which does not record the currency.",
+                data_type.to_uppercase()
+            ))
+            .unwrap()
+            .resolution(format!(
+                "Declare column '{table_name}.{column_name}' as `NUMERIC(precision, scale)`, with a separate currency column if amounts may be in several currencies."
+            ))
+            .unwrap()
+            .try_into()
+            .unwrap();
+        Err(crate::error::Error::Column(
+            Box::new(column.clone()),
+            error.into(),
+        ))
+    }
+}
@@ -0,0 +1,113 @@
+//! Submodule providing the `NoNullableBoolean` constraint, which enforces
+//! that boolean columns are `NOT NULL`.
+
+use sql_traits::traits::{ColumnLike, DatabaseLike, TableLike};
+
+use crate::{
+    error::{ObjectRef, RuleErrorInfo},
+    traits::{ColumnRule, Constrainer, GenericConstrainer},
+};
+
+/// Struct defining a constraint that enforces that boolean columns are
+/// `NOT NULL`, since a nullable boolean has three values and makes
+/// conditions such as `NOT is_active` silently skip the `NULL` rows.
+///
+/// # Example
+///
+/// Here follows an example of validating an invalid SQL statement with the
+/// `NoNullableBoolean` constraint.
+///
+/// ```rust
+/// use sql_rules::prelude::*;
+/// use sqlparser::dialect::GenericDialect;
+///
+/// let constrainer: GenericConstrainer<ParserDB> = NoNullableBoolean::default().into();
+///
+/// let invalid_schema =
+///     ParserDB::parse::<GenericDialect>("CREATE TABLE users (id INT PRIMARY KEY, is_active BOOLEAN);")
+///         .unwrap();
+/// assert!(constrainer.validate_schema(&invalid_schema).is_err());
+///
+/// let valid_schema = ParserDB::parse::<GenericDialect>(
+///     "CREATE TABLE users (id INT PRIMARY KEY, is_active BOOLEAN NOT NULL DEFAULT TRUE);",
+/// )
+/// .unwrap();
+/// assert!(constrainer.validate_schema(&valid_schema).is_ok());
+///
+/// // Columns where NULL genuinely means "unknown" can be exempted
+/// let constrainer: GenericConstrainer<ParserDB> =
+///     NoNullableBoolean::default().with_exemption("is_active").into();
+/// assert!(constrainer.validate_schema(&invalid_schema).is_ok());
+/// ```
+pub struct NoNullableBoolean<DB> {
+    exemptions: Vec<String>,
+    _phantom: std::marker::PhantomData<DB>,
+}
+
+impl<DB> NoNullableBoolean<DB> {
+    /// Adds the name of a column exempted from the rule.
+    #[must_use]
+    pub fn with_exemption(mut self, column_name: impl Into<String>) -> Self {
+        self.exemptions.push(column_name.into());
+        self
+    }
+}
+
+impl<DB> Default for NoNullableBoolean<DB> {
+    fn default() -> Self {
+        Self {
+            exemptions: Vec::new(),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<DB: DatabaseLike + 'static> From<NoNullableBoolean<DB>> for GenericConstrainer<DB> {
+    fn from(constraint: NoNullableBoolean<DB>) -> Self {
+        let mut constrainer = GenericConstrainer::default();
+        constrainer.register_column_rule(Box::new(constraint));
+        constrainer
+    }
+}
+
+impl<DB: DatabaseLike> ColumnRule for NoNullableBoolean<DB> {
+    type Database = DB;
+
+    fn validate_column(
+        &self,
+        database: &Self::Database,
+        column: &<Self::Database as DatabaseLike>::Column,
+    ) -> Result<(), crate::error::Error<DB>> {
+        let column_name = column.column_name();
+        if !column.is_boolean(database)
+            || !column.is_nullable(database)
+            || self
+                .exemptions
+                .iter()
+                .any(|exemption| exemption.eq_ignore_ascii_case(column_name))
+        {
+            return Ok(());
+        }
+
+        let table_name = column.table(database).table_name();
+        let error: RuleErrorInfo = RuleErrorInfo::builder()
+            .rule("NoNullableBoolean")
+            .unwrap()
+            .object(ObjectRef::column(database, column))
+            .unwrap()
+            .message(format!(
+                "Boolean column '{table_name}.{column_name}' is nullable, so it has three values (TRUE, FALSE and NULL)."
+            ))
+            .unwrap()
+            .resolution(format!(
+                "Declare column '{table_name}.{column_name}' as NOT NULL with a DEFAULT, or replace it with an enumeration if 'unknown' is a meaningful state."
+            ))
+            .unwrap()
+            .try_into()
+            .unwrap();
+        Err(crate::error::Error::Column(
+            Box::new(column.clone()),
+            error.into(),
+        ))
+    }
+}
@@ -5,7 +5,7 @@
 use sql_traits::traits::{ColumnLike, DatabaseLike, TableLike};
 
 use crate::{
-    error::RuleErrorInfo,
+    error::{ObjectRef, RuleErrorInfo},
     traits::{ColumnRule, Constrainer, GenericConstrainer},
 };
 
@@ -108,7 +108,7 @@ impl<DB: DatabaseLike> ColumnRule for NoSurrogatePrimaryKeyInExtension<DB> {
         let error: RuleErrorInfo = RuleErrorInfo::builder()
             .rule("NoSurrogatePrimaryKeyInExtension")
             .unwrap()
-            .object(format!("{table_name}.{column_name}"))
+            .object(ObjectRef::column(database, column))
             .unwrap()
             .message(format!(
                 "Primary-key column '{table_name}.{column_name}' belongs to an extension table and {surrogate_reason}"
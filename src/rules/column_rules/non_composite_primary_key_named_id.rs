@@ -5,7 +5,7 @@
 use sql_traits::traits::{ColumnLike, DatabaseLike, TableLike};
 
 use crate::{
-    error::RuleErrorInfo,
+    error::{ObjectRef, RuleErrorInfo},
     traits::{ColumnRule, Constrainer, GenericConstrainer},
 };
 
@@ -88,7 +88,7 @@ impl<DB: DatabaseLike> ColumnRule for NonCompositePrimaryKeyNamedId<DB> {
             let error: RuleErrorInfo = RuleErrorInfo::builder()
                 .rule("NonCompositePrimaryKeyNamedId")
                 .unwrap()
-                .object(format!("{table_name}.{column_name}"))
+                .object(ObjectRef::column(database, column))
                 .unwrap()
                 .message(format!(
                     "Column '{column_name}' in table '{table_name}' is a non-composite primary key but is not named 'id'"
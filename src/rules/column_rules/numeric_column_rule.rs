@@ -0,0 +1,199 @@
+//! Submodule providing the `NumericColumnRule` rule.
+
+use sql_traits::traits::{CheckConstraintLike, ColumnLike, DatabaseLike, TableLike};
+
+use crate::{
+    error::{ObjectRef, RuleErrorInfo},
+    rules::{
+        data_types::is_numeric,
+        expression_analysis::{Comparison, Interval, constraint_bounds},
+        name_pattern::matches_glob,
+    },
+    traits::{ColumnRule, Constrainer, GenericConstrainer},
+};
+
+/// Data types of the numeric columns, lowercased.
+/// Struct defining a rule that enforces domain constraints on numeric
+/// columns, based on their names.
+///
+/// 1. Numeric columns whose name matches one of the non-negative patterns (by
+///    default `*_count`, `quantity`, `price`, `amount`, `age` and `*_size`)
+///    must have a check constraint ensuring they are non-negative, such as
+///    `CHECK (quantity >= 0)`.
+/// 2. Numeric columns whose name matches one of the positive patterns (none
+///    by default) must have a check constraint ensuring they are positive,
+///    such as `CHECK (quantity > 0)`.
+/// 3. Numeric columns whose name matches a pattern with a configured upper
+///    bound (by default 100 for `percentage` and `*_percentage`) must have a
+///    check constraint ensuring they do not exceed it.
+///
+/// Patterns may use `*` to match any sequence of characters. The bounds may
+/// come from several check constraints, possibly combined with `AND` or
+/// written with `BETWEEN`.
+///
+/// # Example
+///
+/// ```rust
+/// use sql_rules::prelude::*;
+/// use sqlparser::dialect::GenericDialect;
+///
+/// let constrainer: GenericConstrainer<ParserDB> = NumericColumnRule::default().into();
+///
+/// // Invalid: quantity without a lower bound
+/// let invalid_schema = ParserDB::parse::<GenericDialect>("CREATE TABLE items (quantity INT);").unwrap();
+/// assert!(constrainer.validate_schema(&invalid_schema).is_err());
+///
+/// // Invalid: percentage without an upper bound
+/// let invalid_schema = ParserDB::parse::<GenericDialect>("CREATE TABLE items (discount_percentage INT CHECK (discount_percentage >= 0));").unwrap();
+/// assert!(constrainer.validate_schema(&invalid_schema).is_err());
+///
+/// // Valid: bounded columns
+/// let valid_schema = ParserDB::parse::<GenericDialect>("CREATE TABLE items (view_count BIGINT CHECK (view_count >= 0), discount_percentage INT CHECK (discount_percentage BETWEEN 0 AND 100));").unwrap();
+/// assert!(constrainer.validate_schema(&valid_schema).is_ok());
+///
+/// // Custom patterns
+/// let constrainer: GenericConstrainer<ParserDB> = NumericColumnRule::default()
+///     .with_positive_pattern("*_weight")
+///     .with_upper_bound("rating", 5.0)
+///     .into();
+/// let invalid_schema = ParserDB::parse::<GenericDialect>("CREATE TABLE items (net_weight REAL CHECK (net_weight >= 0));").unwrap();
+/// assert!(constrainer.validate_schema(&invalid_schema).is_err());
+/// let valid_schema = ParserDB::parse::<GenericDialect>("CREATE TABLE items (net_weight REAL CHECK (net_weight > 0), rating INT CHECK (rating <= 5));").unwrap();
+/// assert!(constrainer.validate_schema(&valid_schema).is_ok());
+/// ```
+pub struct NumericColumnRule<DB> {
+    non_negative_patterns: Vec<String>,
+    positive_patterns: Vec<String>,
+    upper_bounds: Vec<(String, f64)>,
+    _phantom: std::marker::PhantomData<DB>,
+}
+
+impl<DB> NumericColumnRule<DB> {
+    /// Adds a pattern of the names of the columns which must be non-negative.
+    #[must_use]
+    pub fn with_non_negative_pattern(mut self, pattern: impl Into<String>) -> Self {
+        self.non_negative_patterns.push(pattern.into());
+        self
+    }
+
+    /// Adds a pattern of the names of the columns which must be positive.
+    #[must_use]
+    pub fn with_positive_pattern(mut self, pattern: impl Into<String>) -> Self {
+        self.positive_patterns.push(pattern.into());
+        self
+    }
+
+    /// Adds a pattern of the names of the columns which must not exceed the
+    /// provided upper bound.
+    #[must_use]
+    pub fn with_upper_bound(mut self, pattern: impl Into<String>, upper_bound: f64) -> Self {
+        self.upper_bounds.push((pattern.into(), upper_bound));
+        self
+    }
+
+    /// Returns the bounds required on the column with the provided name, as
+    /// the comparisons and values the column must satisfy.
+    fn required_bounds(&self, column_name: &str) -> Vec<(Comparison, f64)> {
+        let matches_any = |patterns: &[String]| {
+            patterns
+                .iter()
+                .any(|pattern| matches_glob(pattern, column_name))
+        };
+        let mut required_bounds = Vec::new();
+        if matches_any(&self.positive_patterns) {
+            required_bounds.push((Comparison::Gt, 0.0));
+        } else if matches_any(&self.non_negative_patterns) {
+            required_bounds.push((Comparison::GtEq, 0.0));
+        }
+        required_bounds.extend(
+            self.upper_bounds
+                .iter()
+                .filter(|(pattern, _)| matches_glob(pattern, column_name))
+                .map(|&(_, upper_bound)| (Comparison::LtEq, upper_bound)),
+        );
+        required_bounds
+    }
+}
+
+impl<DB> Default for NumericColumnRule<DB> {
+    fn default() -> Self {
+        Self {
+            non_negative_patterns: ["*_count", "quantity", "price", "amount", "age", "*_size"]
+                .map(String::from)
+                .to_vec(),
+            positive_patterns: Vec::new(),
+            upper_bounds: vec![
+                ("percentage".to_string(), 100.0),
+                ("*_percentage".to_string(), 100.0),
+            ],
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<DB: DatabaseLike + 'static> From<NumericColumnRule<DB>> for GenericConstrainer<DB> {
+    fn from(rule: NumericColumnRule<DB>) -> Self {
+        let mut constrainer = GenericConstrainer::default();
+        constrainer.register_column_rule(Box::new(rule));
+        constrainer
+    }
+}
+
+impl<DB: DatabaseLike> ColumnRule for NumericColumnRule<DB> {
+    type Database = DB;
+
+    fn validate_column(
+        &self,
+        database: &Self::Database,
+        column: &<Self::Database as DatabaseLike>::Column,
+    ) -> Result<(), crate::error::Error<DB>> {
+        let data_type = column.normalized_data_type(database).to_lowercase();
+        if !is_numeric(&data_type) {
+            return Ok(());
+        }
+
+        let column_name = column.column_name();
+        let subject = column_name.to_lowercase();
+        let interval = column
+            .check_constraints(database)
+            .filter_map(|cc| {
+                constraint_bounds(cc.expression(database))
+                    .intervals
+                    .get(&subject)
+                    .copied()
+            })
+            .fold(Interval::default(), Interval::intersection);
+
+        for (comparison, value) in self.required_bounds(column_name) {
+            let Some(required) = Interval::from_comparison(comparison, value) else {
+                continue;
+            };
+            if required.contains(&interval) {
+                continue;
+            }
+
+            let table_name = column.table(database).table_name();
+            let requirement = format!("{column_name} {} {value}", comparison.symbol());
+            let error: RuleErrorInfo = RuleErrorInfo::builder()
+                .rule("NumericColumnRule")
+                .unwrap()
+                .object(ObjectRef::column(database, column))
+                .unwrap()
+                .message(format!(
+                    "Column '{table_name}.{column_name}' is not constrained to `{requirement}`."
+                ))
+                .unwrap()
+                .resolution(format!(
+                    "Add a check constraint ensuring the bound (e.g. `CHECK ({requirement})`)."
+                ))
+                .unwrap()
+                .try_into()
+                .unwrap();
+            return Err(crate::error::Error::Column(
+                Box::new(column.clone()),
+                error.into(),
+            ));
+        }
+        Ok(())
+    }
+}
@@ -1,15 +1,34 @@
 //! Submodule providing the `PastTimeColumnRule` rule.
 
+use sql_traits::traits::{CheckConstraintLike, ColumnLike, DatabaseLike, TableLike};
+use sqlparser::ast::Expr;
+
 use crate::{
-    error::RuleErrorInfo,
+    error::{ObjectRef, RuleErrorInfo},
+    rules::{
+        data_types::{TIME_DATA_TYPES, base_type},
+        expression_analysis::{
+            Comparison, column_comparison, conjuncts, is_column_reference, is_current_time,
+        },
+    },
     traits::{ColumnRule, Constrainer, GenericConstrainer},
 };
-use sql_traits::traits::{CheckConstraintLike, ColumnLike, DatabaseLike, TableLike};
 
-/// Struct defining a rule that enforces that time-related columns must have a check constraint ensuring they are in the past.
+/// Data types of the timestamp and date columns, lowercased and without
+/// precision or time zone.
+/// Struct defining a rule that enforces that time-related columns must have a
+/// check constraint ensuring they are in the past.
 ///
-/// This rule checks if a column name ends with `_at` (e.g., `created_at`, `updated_at`).
-/// If so, it requires a check constraint involving `NOW()` or `CURRENT_TIMESTAMP` and a less-than operator.
+/// A column is considered time-related when its name ends with one of the
+/// configured suffixes (by default `_at`, `_on` and `_date`), it is not one of
+/// the configured exemptions (by default columns such as `expires_at` or
+/// `due_date`, which usually hold future values) and its data type is a
+/// timestamp or a date.
+///
+/// Such a column must have a check constraint bounding it from above by the
+/// current time, such as `created_at <= NOW()`, `CURRENT_DATE >= created_on`
+/// or `created_at BETWEEN '2000-01-01' AND CURRENT_TIMESTAMP`, possibly as
+/// one of the operands of a conjunction.
 ///
 /// # Example
 ///
@@ -23,15 +42,111 @@ use sql_traits::traits::{CheckConstraintLike, ColumnLike, DatabaseLike, TableLik
 /// let invalid_schema = ParserDB::parse::<GenericDialect>("CREATE TABLE users (created_at TIMESTAMP);").unwrap();
 /// assert!(constrainer.validate_schema(&invalid_schema).is_err());
 ///
+/// // Invalid: the constraint bounds created_at to the future
+/// let invalid_schema = ParserDB::parse::<GenericDialect>("CREATE TABLE users (created_at TIMESTAMP CHECK (NOW() < created_at));").unwrap();
+/// assert!(constrainer.validate_schema(&invalid_schema).is_err());
+///
 /// // Valid: created_at with constraint
 /// let valid_schema = ParserDB::parse::<GenericDialect>("CREATE TABLE users (created_at TIMESTAMP CHECK (created_at <= NOW()));").unwrap();
 /// assert!(constrainer.validate_schema(&valid_schema).is_ok());
+///
+/// // Valid: date columns may be compared with CURRENT_DATE
+/// let valid_schema = ParserDB::parse::<GenericDialect>("CREATE TABLE users (birth_date DATE CHECK (birth_date <= CURRENT_DATE));").unwrap();
+/// assert!(constrainer.validate_schema(&valid_schema).is_ok());
+///
+/// // Valid: columns which are not timestamps or dates are ignored
+/// let valid_schema = ParserDB::parse::<GenericDialect>("CREATE TABLE users (seen_at TEXT);").unwrap();
+/// assert!(constrainer.validate_schema(&valid_schema).is_ok());
+///
+/// // Valid: date ranges cannot be bounded by the current time
+/// let valid_schema = ParserDB::parse::<GenericDialect>("CREATE TABLE prices (valid_at DATERANGE);").unwrap();
+/// assert!(constrainer.validate_schema(&valid_schema).is_ok());
+///
+/// // Custom suffixes and exemptions
+/// let constrainer: GenericConstrainer<ParserDB> = PastTimeColumnRule::default()
+///     .with_suffixes(["_timestamp"])
+///     .with_exemption("next_timestamp")
+///     .into();
+/// let invalid_schema = ParserDB::parse::<GenericDialect>("CREATE TABLE users (login_timestamp TIMESTAMP);").unwrap();
+/// assert!(constrainer.validate_schema(&invalid_schema).is_err());
+/// let valid_schema = ParserDB::parse::<GenericDialect>("CREATE TABLE users (created_at TIMESTAMP, next_timestamp TIMESTAMP);").unwrap();
+/// assert!(constrainer.validate_schema(&valid_schema).is_ok());
 /// ```
-pub struct PastTimeColumnRule<DB>(std::marker::PhantomData<DB>);
+pub struct PastTimeColumnRule<DB> {
+    suffixes: Vec<String>,
+    exemptions: Vec<String>,
+    _phantom: std::marker::PhantomData<DB>,
+}
+
+impl<DB> PastTimeColumnRule<DB> {
+    /// Replaces the suffixes identifying time-related columns.
+    #[must_use]
+    pub fn with_suffixes<S: Into<String>>(mut self, suffixes: impl IntoIterator<Item = S>) -> Self {
+        self.suffixes = suffixes.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Adds a suffix identifying time-related columns.
+    #[must_use]
+    pub fn with_suffix(mut self, suffix: impl Into<String>) -> Self {
+        self.suffixes.push(suffix.into());
+        self
+    }
+
+    /// Replaces the names of the columns exempted from the rule.
+    #[must_use]
+    pub fn with_exemptions<S: Into<String>>(
+        mut self,
+        exemptions: impl IntoIterator<Item = S>,
+    ) -> Self {
+        self.exemptions = exemptions.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Adds the name of a column exempted from the rule.
+    #[must_use]
+    pub fn with_exemption(mut self, exemption: impl Into<String>) -> Self {
+        self.exemptions.push(exemption.into());
+        self
+    }
+
+    /// Returns whether the column with the provided name and data type is
+    /// subject to the rule.
+    fn applies_to(&self, column_name: &str, data_type: &str) -> bool {
+        let column_name = column_name.to_lowercase();
+        let data_type = data_type.to_lowercase();
+        self.suffixes
+            .iter()
+            .any(|suffix| column_name.ends_with(&suffix.to_lowercase()))
+            && !self
+                .exemptions
+                .iter()
+                .any(|exemption| exemption.eq_ignore_ascii_case(&column_name))
+            && TIME_DATA_TYPES.contains(&base_type(&data_type))
+    }
+}
 
 impl<DB> Default for PastTimeColumnRule<DB> {
     fn default() -> Self {
-        Self(std::marker::PhantomData)
+        Self {
+            suffixes: ["_at", "_on", "_date"].map(String::from).to_vec(),
+            exemptions: [
+                "expires_at",
+                "due_at",
+                "starts_at",
+                "ends_at",
+                "scheduled_at",
+                "expires_on",
+                "due_on",
+                "due_date",
+                "expiry_date",
+                "expiration_date",
+                "end_date",
+            ]
+            .map(String::from)
+            .to_vec(),
+            _phantom: std::marker::PhantomData,
+        }
     }
 }
 
@@ -43,6 +158,48 @@ impl<DB: DatabaseLike + 'static> From<PastTimeColumnRule<DB>> for GenericConstra
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Direction in which a check constraint bounds a column with respect to the
+/// current time.
+enum TimeBound {
+    /// The column must not be after the current time.
+    Past,
+    /// The column must not be before the current time.
+    Future,
+}
+
+/// Returns how the provided expression bounds the column with the provided
+/// name with respect to the current time, if at all.
+fn time_bound(expr: &Expr, column_name: &str) -> Option<TimeBound> {
+    if let Some((comparison, other)) = column_comparison(expr, column_name) {
+        if !is_current_time(other) {
+            return None;
+        }
+        return match comparison {
+            Comparison::Lt | Comparison::LtEq => Some(TimeBound::Past),
+            Comparison::Gt | Comparison::GtEq => Some(TimeBound::Future),
+            Comparison::Eq | Comparison::NotEq => None,
+        };
+    }
+    match expr {
+        Expr::Between {
+            expr,
+            negated: false,
+            low,
+            high,
+        } if is_column_reference(expr, column_name) => {
+            if is_current_time(high) {
+                Some(TimeBound::Past)
+            } else if is_current_time(low) {
+                Some(TimeBound::Future)
+            } else {
+                None
+            }
+        }
+        _ => None,
+    }
+}
+
 impl<DB: DatabaseLike> ColumnRule for PastTimeColumnRule<DB> {
     type Database = DB;
 
@@ -52,59 +209,56 @@ impl<DB: DatabaseLike> ColumnRule for PastTimeColumnRule<DB> {
         column: &<Self::Database as DatabaseLike>::Column,
     ) -> Result<(), crate::error::Error<DB>> {
         let column_name = column.column_name();
+        let data_type = column.normalized_data_type(database);
 
-        // Identify time-related columns by suffix "_at"
-        if !column_name.ends_with("_at") {
+        if !self.applies_to(column_name, data_type) {
             return Ok(());
         }
 
-        // Exclude common future or interval boundary columns
-        let future_or_ambiguous = [
-            "expires_at",
-            "due_at",
-            "starts_at",
-            "ends_at",
-            "scheduled_at",
-        ];
-        if future_or_ambiguous.contains(&column_name) {
-            return Ok(());
-        }
+        let bounds: Vec<TimeBound> = column
+            .check_constraints(database)
+            .flat_map(|cc| {
+                conjuncts(cc.expression(database))
+                    .into_iter()
+                    .filter_map(|expr| time_bound(expr, column_name))
+            })
+            .collect();
 
-        let has_past_check = column.check_constraints(database).any(|cc| {
-            let expr = cc.expression(database).to_string().to_lowercase();
-
-            // Check for "now()" or "current_timestamp"
-            let mentions_now = expr.contains("now()") || expr.contains("current_timestamp");
-
-            // Check for less than operator
-            let has_lt = expr.contains('<');
-
-            mentions_now && has_lt
-        });
-
-        if !has_past_check {
-            let table_name = column.table(database).table_name();
-            let error: RuleErrorInfo = RuleErrorInfo::builder()
-                .rule("PastTimeColumnRule")
-                .unwrap()
-                .object(format!("{table_name}.{column_name}"))
-                .unwrap()
-                .message(format!(
-                    "Time-related column '{table_name}.{column_name}' must have a check constraint ensuring it is in the past."
-                ))
-                .unwrap()
-                .resolution(format!(
-                    "Add a check constraint like `CHECK ({column_name} <= NOW())`."
-                ))
-                .unwrap()
-                .try_into()
-                .unwrap();
-            return Err(crate::error::Error::Column(
-                Box::new(column.clone()),
-                error.into(),
-            ));
+        if bounds.contains(&TimeBound::Past) {
+            return Ok(());
         }
 
-        Ok(())
+        let table_name = column.table(database).table_name();
+        let current_time = if data_type.eq_ignore_ascii_case("date") {
+            "CURRENT_DATE"
+        } else {
+            "NOW()"
+        };
+        let message = if bounds.contains(&TimeBound::Future) {
+            format!(
+                "Time-related column '{table_name}.{column_name}' has a check constraint comparing it with the current time, but the column is on the wrong side of the comparison and is bounded to the future."
+            )
+        } else {
+            format!(
+                "Time-related column '{table_name}.{column_name}' must have a check constraint ensuring it is in the past."
+            )
+        };
+        let error: RuleErrorInfo = RuleErrorInfo::builder()
+            .rule("PastTimeColumnRule")
+            .unwrap()
+            .object(ObjectRef::column(database, column))
+            .unwrap()
+            .message(message)
+            .unwrap()
+            .resolution(format!(
+                "Add a check constraint like `CHECK ({column_name} <= {current_time})`, or exempt the column if it may legitimately hold future values."
+            ))
+            .unwrap()
+            .try_into()
+            .unwrap();
+        Err(crate::error::Error::Column(
+            Box::new(column.clone()),
+            error.into(),
+        ))
     }
 }
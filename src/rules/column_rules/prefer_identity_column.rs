@@ -0,0 +1,106 @@
+//! Submodule providing the `PreferIdentityColumn` constraint, which enforces
+//! that auto-incrementing columns are identity columns rather than `SERIAL`.
+
+use sql_traits::traits::{ColumnLike, DatabaseLike, TableLike};
+
+use crate::{
+    error::{ObjectRef, RuleErrorInfo},
+    traits::{ColumnRule, Constrainer, GenericConstrainer},
+};
+
+/// Pseudo-types declaring auto-incrementing columns, lowercased, with the
+/// integer type they stand for.
+const SERIAL_DATA_TYPES: &[(&str, &str)] = &[
+    ("smallserial", "SMALLINT"),
+    ("serial2", "SMALLINT"),
+    ("serial", "INTEGER"),
+    ("serial4", "INTEGER"),
+    ("bigserial", "BIGINT"),
+    ("serial8", "BIGINT"),
+];
+
+/// Struct defining a constraint that enforces that auto-incrementing columns
+/// are declared with the standard `GENERATED ... AS IDENTITY` syntax rather
+/// than with the `SERIAL` pseudo-types, whose sequences are separate objects
+/// with their own ownership and permissions.
+///
+/// Since the normalized data type of a `SERIAL` column is its integer type,
+/// this rule inspects the declared data type of the columns.
+///
+/// # Example
+///
+/// Here follows an example of validating an invalid SQL statement with the
+/// `PreferIdentityColumn` constraint.
+///
+/// ```rust
+/// use sql_rules::prelude::*;
+/// use sqlparser::dialect::GenericDialect;
+///
+/// let constrainer: GenericConstrainer<ParserDB> = PreferIdentityColumn::default().into();
+///
+/// let invalid_schema =
+///     ParserDB::parse::<GenericDialect>("CREATE TABLE users (id BIGSERIAL PRIMARY KEY);").unwrap();
+/// assert!(constrainer.validate_schema(&invalid_schema).is_err());
+///
+/// let valid_schema = ParserDB::parse::<GenericDialect>(
+///     "CREATE TABLE users (id BIGINT GENERATED ALWAYS AS IDENTITY PRIMARY KEY);",
+/// )
+/// .unwrap();
+/// assert!(constrainer.validate_schema(&valid_schema).is_ok());
+/// ```
+pub struct PreferIdentityColumn<DB>(std::marker::PhantomData<DB>);
+
+impl<DB> Default for PreferIdentityColumn<DB> {
+    fn default() -> Self {
+        Self(std::marker::PhantomData)
+    }
+}
+
+impl<DB: DatabaseLike + 'static> From<PreferIdentityColumn<DB>> for GenericConstrainer<DB> {
+    fn from(constraint: PreferIdentityColumn<DB>) -> Self {
+        let mut constrainer = GenericConstrainer::default();
+        constrainer.register_column_rule(Box::new(constraint));
+        constrainer
+    }
+}
+
+impl<DB: DatabaseLike> ColumnRule for PreferIdentityColumn<DB> {
+    type Database = DB;
+
+    fn validate_column(
+        &self,
+        database: &Self::Database,
+        column: &<Self::Database as DatabaseLike>::Column,
+    ) -> Result<(), crate::error::Error<DB>> {
+        let data_type = column.data_type(database).to_lowercase();
+        let Some((serial_type, integer_type)) = SERIAL_DATA_TYPES
+            .iter()
+            .find(|(serial_type, _)| data_type == *serial_type)
+        else {
+            return Ok(());
+        };
+
+        let table_name = column.table(database).table_name();
+        let column_name = column.column_name();
+        let error: RuleErrorInfo = RuleErrorInfo::builder()
+            .rule("PreferIdentityColumn")
+            .unwrap()
+            .object(ObjectRef::column(database, column))
+            .unwrap()
+            .message(format!(
+                "Column '{table_name}.{column_name}' is declared with the `{}` pseudo-type, which creates a separately owned sequence.",
+                serial_type.to_uppercase()
+            ))
+            .unwrap()
+            .resolution(format!(
+                "Declare column '{table_name}.{column_name}' as `{integer_type} GENERATED ALWAYS AS IDENTITY` (or `GENERATED BY DEFAULT AS IDENTITY`)."
+            ))
+            .unwrap()
+            .try_into()
+            .unwrap();
+        Err(crate::error::Error::Column(
+            Box::new(column.clone()),
+            error.into(),
+        ))
+    }
+}
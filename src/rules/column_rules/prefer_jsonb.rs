@@ -0,0 +1,116 @@
+//! Submodule providing the `PreferJsonb` constraint, which enforces that JSON
+//! columns use the binary `JSONB` representation.
+
+use sql_traits::traits::{ColumnLike, DatabaseLike, TableLike};
+
+use crate::{
+    error::{ObjectRef, RuleErrorInfo},
+    traits::{ColumnRule, Constrainer, GenericConstrainer},
+};
+
+/// Struct defining a constraint that enforces that JSON columns are declared
+/// as `JSONB` rather than `JSON`, which is stored as text, re-parsed on every
+/// access and cannot be indexed with GIN indices.
+///
+/// Columns which must preserve the exact input text (key order, duplicate
+/// keys, whitespace) can be exempted.
+///
+/// # Example
+///
+/// Here follows an example of validating an invalid SQL statement with the
+/// `PreferJsonb` constraint.
+///
+/// ```rust
+/// use sql_rules::prelude::*;
+/// use sqlparser::dialect::GenericDialect;
+///
+/// let constrainer: GenericConstrainer<ParserDB> = PreferJsonb::default().into();
+///
+/// let invalid_schema =
+///     ParserDB::parse::<GenericDialect>("CREATE TABLE events (id INT PRIMARY KEY, payload JSON);")
+///         .unwrap();
+/// assert!(constrainer.validate_schema(&invalid_schema).is_err());
+///
+/// let valid_schema =
+///     ParserDB::parse::<GenericDialect>("CREATE TABLE events (id INT PRIMARY KEY, payload JSONB);")
+///         .unwrap();
+/// assert!(constrainer.validate_schema(&valid_schema).is_ok());
+///
+/// // Columns preserving the raw input can be exempted
+/// let constrainer: GenericConstrainer<ParserDB> =
+///     PreferJsonb::default().with_exemption("payload").into();
+/// assert!(constrainer.validate_schema(&invalid_schema).is_ok());
+/// ```
+pub struct PreferJsonb<DB> {
+    exemptions: Vec<String>,
+    _phantom: std::marker::PhantomData<DB>,
+}
+
+impl<DB> PreferJsonb<DB> {
+    /// Adds the name of a column exempted from the rule.
+    #[must_use]
+    pub fn with_exemption(mut self, column_name: impl Into<String>) -> Self {
+        self.exemptions.push(column_name.into());
+        self
+    }
+}
+
+impl<DB> Default for PreferJsonb<DB> {
+    fn default() -> Self {
+        Self {
+            exemptions: Vec::new(),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<DB: DatabaseLike + 'static> From<PreferJsonb<DB>> for GenericConstrainer<DB> {
+    fn from(constraint: PreferJsonb<DB>) -> Self {
+        let mut constrainer = GenericConstrainer::default();
+        constrainer.register_column_rule(Box::new(constraint));
+        constrainer
+    }
+}
+
+impl<DB: DatabaseLike> ColumnRule for PreferJsonb<DB> {
+    type Database = DB;
+
+    fn validate_column(
+        &self,
+        database: &Self::Database,
+        column: &<Self::Database as DatabaseLike>::Column,
+    ) -> Result<(), crate::error::Error<DB>> {
+        let column_name = column.column_name();
+        if !column
+            .normalized_data_type(database)
+            .eq_ignore_ascii_case("json")
+            || self
+                .exemptions
+                .iter()
+                .any(|exemption| exemption.eq_ignore_ascii_case(column_name))
+        {
+            return Ok(());
+        }
+
+        let table_name = column.table(database).table_name();
+        let error: RuleErrorInfo = RuleErrorInfo::builder()
+            .rule("PreferJsonb")
+            .unwrap()
+            .object(ObjectRef::column(database, column))
+            .unwrap()
+            .message(format!(
+                "Column '{table_name}.{column_name}' is declared as `JSON`, which is stored as text and re-parsed on every access."
+            ))
+            .unwrap()
+            .resolution(format!(
+                "Declare column '{table_name}.{column_name}' as `JSONB`, or exempt it if the exact input text must be preserved."
+            ))
+            .unwrap()
+            .try_into()
+            .unwrap();
+        Err(crate::error::Error::Column(
+            Box::new(column.clone()),
+            error.into(),
+        ))
+    }
+}
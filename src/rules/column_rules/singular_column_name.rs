@@ -1,11 +1,11 @@
 //! Submodule providing the `SingularColumnName` constraint, which enforces
 //! that the last segment of column names is singular.
 
-use inflection_rs::inflection::singularize;
 use sql_traits::traits::{ColumnLike, DatabaseLike, TableLike};
 
 use crate::{
-    error::RuleErrorInfo,
+    error::{ObjectRef, RuleErrorInfo},
+    rules::InflectionDictionary,
     traits::{ColumnRule, Constrainer, GenericConstrainer},
 };
 
@@ -13,7 +13,11 @@ use crate::{
 /// names is singular.
 ///
 /// For column names with underscores (e.g., `user_account`), only the last
-/// segment after the final underscore is checked for singularity.
+/// segment after the final underscore is checked for singularity, unless the
+/// name ends with one of the compound suffixes of the [`InflectionDictionary`].
+/// The dictionary also provides the uncountable and irregular words, by
+/// default a scientific vocabulary, so that columns such as `metadata` or
+/// `status` are not flagged.
 ///
 /// # Example
 ///
@@ -58,12 +62,45 @@ use crate::{
 ///
 /// let invalid_taxa = ParserDB::parse::<GenericDialect>("CREATE TABLE mytable (taxa INT);").unwrap();
 /// assert!(constrainer.validate_schema(&invalid_taxa).is_err());
+///
+/// // Uncountable and irregular scientific words
+/// let valid_schema3 = ParserDB::parse::<GenericDialect>("CREATE TABLE mytable (metadata TEXT, status TEXT);").unwrap();
+/// assert!(constrainer.validate_schema(&valid_schema3).is_ok());
+///
+/// // Custom dictionary with a compound suffix
+/// let constrainer: GenericConstrainer<ParserDB> = SingularColumnName::default()
+///     .with_dictionary(
+///         InflectionDictionary::default()
+///             .with_irregular("mother_in_law", "mothers_in_law")
+///             .with_compound_suffix("mother_in_law")
+///             .with_compound_suffix("mothers_in_law"),
+///     )
+///     .into();
+/// let invalid_schema3 = ParserDB::parse::<GenericDialect>("CREATE TABLE mytable (mothers_in_law TEXT);").unwrap();
+/// assert!(constrainer.validate_schema(&invalid_schema3).is_err());
+/// let valid_schema4 = ParserDB::parse::<GenericDialect>("CREATE TABLE mytable (mother_in_law TEXT);").unwrap();
+/// assert!(constrainer.validate_schema(&valid_schema4).is_ok());
 /// ```
-pub struct SingularColumnName<DB>(std::marker::PhantomData<DB>);
+pub struct SingularColumnName<DB> {
+    dictionary: InflectionDictionary,
+    _phantom: std::marker::PhantomData<DB>,
+}
+
+impl<DB> SingularColumnName<DB> {
+    /// Sets the dictionary of the exceptions to the inflection rules.
+    #[must_use]
+    pub fn with_dictionary(mut self, dictionary: InflectionDictionary) -> Self {
+        self.dictionary = dictionary;
+        self
+    }
+}
 
 impl<DB> Default for SingularColumnName<DB> {
     fn default() -> Self {
-        Self(std::marker::PhantomData)
+        Self {
+            dictionary: InflectionDictionary::default(),
+            _phantom: std::marker::PhantomData,
+        }
     }
 }
 
@@ -84,28 +121,24 @@ impl<DB: DatabaseLike> ColumnRule for SingularColumnName<DB> {
         column: &<Self::Database as DatabaseLike>::Column,
     ) -> Result<(), crate::error::Error<Self::Database>> {
         let column_name = column.column_name();
-        let last_segment = column_name.split('_').next_back().unwrap_or(column_name);
+        let last_segment = self.dictionary.last_segment(column_name);
 
         // Check if the last segment is singular by verifying that singularizing it
         // doesn't change it
-        let singularized = singularize(last_segment);
+        let singularized = self.dictionary.singularize(last_segment);
 
         if singularized == last_segment {
             Ok(())
         } else {
             let table = column.table(database);
             let table_name = table.table_name();
-            let expected_name = if column_name.contains('_') {
-                let prefix = &column_name[..column_name.rfind('_').unwrap()];
-                format!("{}_{}", prefix, &singularized)
-            } else {
-                singularized.clone()
-            };
+            let prefix = &column_name[..column_name.len() - last_segment.len()];
+            let expected_name = format!("{prefix}{singularized}");
 
             let error: RuleErrorInfo = RuleErrorInfo::builder()
                 .rule("SingularColumnName")
                 .unwrap()
-                .object(format!("{table_name}.{column_name}"))
+                .object(ObjectRef::column(database, column))
                 .unwrap()
                 .message(format!(
                     "Column '{column_name}' in table '{table_name}' violates singular naming convention: the last segment '{last_segment}' is plural, not singular"
@@ -1,18 +1,30 @@
 //! Submodule providing the `TextualColumnRule` rule.
 
 use crate::{
-    error::RuleErrorInfo,
+    error::{ObjectRef, RuleErrorInfo},
     traits::{ColumnRule, Constrainer, GenericConstrainer},
 };
 use sql_traits::traits::IndexLike;
 use sql_traits::traits::{CheckConstraintLike, ColumnLike, DatabaseLike, TableLike};
 
+/// Default maximal length of textual columns appearing in the primary key or
+/// in a unique index.
+const DEFAULT_INDEXED_LENGTH_LIMIT: usize = 255;
+
+/// Default maximal length of all other textual columns.
+const DEFAULT_LENGTH_LIMIT: usize = 8192;
+
 /// Struct defining a rule that enforces constraints on textual columns.
 ///
 /// 1. If a column is textual (method `is_textual` returns true), it must have a check constraint that verifies it is not empty.
-/// 2. All textual columns should have also an upper bound check constraint length.
-///    - If they appear in an index, they cannot be longer than 255 characters.
-///    - If they do not appear in an index, they cannot be longer than 8K characters.
+/// 2. All textual columns should have also an upper bound length, either from a check constraint or from the declared
+///    type (e.g. `VARCHAR(100)`). The tightest of the available bounds is used.
+///    - If they appear in the primary key or in a unique index, they cannot be longer than 255 characters by default.
+///    - Otherwise, they cannot be longer than 8K characters by default.
+///
+/// Both thresholds are configurable, and columns meant to hold documents can be
+/// opted out of the upper bound requirements with
+/// [`with_document_column`](Self::with_document_column).
 ///
 /// # Example
 ///
@@ -33,12 +45,79 @@ use sql_traits::traits::{CheckConstraintLike, ColumnLike, DatabaseLike, TableLik
 /// // Valid: Textual column with both constraints
 /// let valid_schema = ParserDB::parse::<GenericDialect>("CREATE TABLE users (name TEXT CHECK (name <> ''), CHECK (LENGTH(name) <= 255));").unwrap();
 /// assert!(constrainer.validate_schema(&valid_schema).is_ok());
+///
+/// // Valid: the declared length of the type is an upper bound
+/// let valid_schema = ParserDB::parse::<GenericDialect>("CREATE TABLE users (name VARCHAR(100) CHECK (name <> ''));").unwrap();
+/// assert!(constrainer.validate_schema(&valid_schema).is_ok());
+///
+/// // Custom thresholds and document columns
+/// let constrainer: GenericConstrainer<ParserDB> = TextualColumnRule::default()
+///     .with_length_limit(1024)
+///     .with_document_column("articles.body")
+///     .into();
+/// let invalid_schema = ParserDB::parse::<GenericDialect>("CREATE TABLE articles (title TEXT CHECK (title <> ''), CHECK (LENGTH(title) <= 2048));").unwrap();
+/// assert!(constrainer.validate_schema(&invalid_schema).is_err());
+/// let valid_schema = ParserDB::parse::<GenericDialect>("CREATE TABLE articles (body TEXT CHECK (body <> ''));").unwrap();
+/// assert!(constrainer.validate_schema(&valid_schema).is_ok());
 /// ```
-pub struct TextualColumnRule<DB>(std::marker::PhantomData<DB>);
+pub struct TextualColumnRule<DB> {
+    indexed_length_limit: usize,
+    length_limit: usize,
+    document_columns: Vec<String>,
+    _phantom: std::marker::PhantomData<DB>,
+}
+
+impl<DB> TextualColumnRule<DB> {
+    /// Sets the maximal length of textual columns appearing in the primary
+    /// key or in a unique index.
+    #[must_use]
+    pub fn with_indexed_length_limit(mut self, limit: usize) -> Self {
+        self.indexed_length_limit = limit;
+        self
+    }
+
+    /// Sets the maximal length of textual columns which do not appear in the
+    /// primary key nor in a unique index.
+    #[must_use]
+    pub fn with_length_limit(mut self, limit: usize) -> Self {
+        self.length_limit = limit;
+        self
+    }
+
+    /// Opts the provided column out of the upper bound length requirements,
+    /// as it is meant to hold documents.
+    ///
+    /// The column may be provided either as `column` to match it in every
+    /// table, or as `table.column` to match it in a single table.
+    #[must_use]
+    pub fn with_document_column(mut self, column: impl Into<String>) -> Self {
+        self.document_columns.push(column.into());
+        self
+    }
+
+    /// Returns whether the column with the provided name, in the table with
+    /// the provided name, is a document column.
+    fn is_document_column(&self, table_name: &str, column_name: &str) -> bool {
+        self.document_columns
+            .iter()
+            .any(|document_column| match document_column.split_once('.') {
+                Some((table, column)) => {
+                    table.eq_ignore_ascii_case(table_name)
+                        && column.eq_ignore_ascii_case(column_name)
+                }
+                None => document_column.eq_ignore_ascii_case(column_name),
+            })
+    }
+}
 
 impl<DB> Default for TextualColumnRule<DB> {
     fn default() -> Self {
-        Self(std::marker::PhantomData)
+        Self {
+            indexed_length_limit: DEFAULT_INDEXED_LENGTH_LIMIT,
+            length_limit: DEFAULT_LENGTH_LIMIT,
+            document_columns: Vec::new(),
+            _phantom: std::marker::PhantomData,
+        }
     }
 }
 
@@ -50,6 +129,14 @@ impl<DB: DatabaseLike + 'static> From<TextualColumnRule<DB>> for GenericConstrai
     }
 }
 
+/// Returns the length declared by a textual data type such as `VARCHAR(100)`,
+/// `CHARACTER VARYING(100)` or `NVARCHAR(100)`, if any.
+fn declared_length(data_type: &str) -> Option<usize> {
+    let (_, arguments) = data_type.split_once('(')?;
+    let (length, _) = arguments.split_once(')')?;
+    length.trim().parse().ok()
+}
+
 impl<DB: DatabaseLike> TextualColumnRule<DB> {
     fn ensure_not_empty_constraint(
         database: &DB,
@@ -65,7 +152,7 @@ impl<DB: DatabaseLike> TextualColumnRule<DB> {
             let error: RuleErrorInfo = RuleErrorInfo::builder()
                 .rule("TextualColumnRule")
                 .unwrap()
-                .object(format!("{table_name}.{column_name}"))
+                .object(ObjectRef::column(database, column))
                 .unwrap()
                 .message(format!(
                     "Textual column '{table_name}.{column_name}' must have a check constraint verifying it is not empty."
@@ -83,24 +170,24 @@ impl<DB: DatabaseLike> TextualColumnRule<DB> {
         Ok(())
     }
 
+    /// Returns the tightest upper bound on the length of the provided textual
+    /// column, considering both its check constraints and its declared type.
+    pub(crate) fn length_upper_bound(
+        database: &DB,
+        column: &<DB as DatabaseLike>::Column,
+    ) -> Option<usize> {
+        column
+            .check_constraints(database)
+            .filter_map(|cc| cc.is_upper_bounded_text_constraint(database))
+            .chain(declared_length(column.normalized_data_type(database)))
+            .min()
+    }
+
     fn ensure_length_constraint_exists(
         database: &DB,
         column: &<DB as DatabaseLike>::Column,
     ) -> Result<usize, crate::error::Error<DB>> {
-        let mut max_length: Option<usize> = None;
-
-        for cc in column.check_constraints(database) {
-            if let Some(limit) = cc.is_upper_bounded_text_constraint(database) {
-                match max_length {
-                    Some(current) => {
-                        if limit < current {
-                            max_length = Some(limit);
-                        }
-                    }
-                    None => max_length = Some(limit),
-                }
-            }
-        }
+        let max_length = Self::length_upper_bound(database, column);
 
         if let Some(limit) = max_length {
             Ok(limit)
@@ -110,14 +197,14 @@ impl<DB: DatabaseLike> TextualColumnRule<DB> {
             let error: RuleErrorInfo = RuleErrorInfo::builder()
                 .rule("TextualColumnRule")
                 .unwrap()
-                .object(format!("{table_name}.{column_name}"))
+                .object(ObjectRef::column(database, column))
                 .unwrap()
                 .message(format!(
                     "Textual column '{table_name}.{column_name}' must have an upper bound length check constraint."
                 ))
                 .unwrap()
                 .resolution(
-                    "Add a length check constraint (e.g. `CHECK (LENGTH(col) <= 255)`)."
+                    "Add a length check constraint (e.g. `CHECK (LENGTH(col) <= 255)`), declare a bounded type (e.g. `VARCHAR(255)`), or mark the column as a document column."
                         .to_string(),
                 )
                 .unwrap()
@@ -131,6 +218,7 @@ impl<DB: DatabaseLike> TextualColumnRule<DB> {
     }
 
     fn ensure_length_limits(
+        &self,
         database: &DB,
         column: &<DB as DatabaseLike>::Column,
         limit: usize,
@@ -139,28 +227,27 @@ impl<DB: DatabaseLike> TextualColumnRule<DB> {
         let column_name = column.column_name();
         let table_name = table.table_name();
 
-        let in_unique_index = table.indices(database).any(|idx| {
+        let in_unique_index = table.unique_indices(database).any(|idx| {
             idx.columns(database)
                 .any(|c| c.column_name() == column_name)
         });
         let in_primary_key = column.is_primary_key(database);
-        let in_index = in_unique_index || in_primary_key;
 
-        if in_index {
-            if limit > 255 {
+        if in_unique_index || in_primary_key {
+            let indexed_length_limit = self.indexed_length_limit;
+            if limit > indexed_length_limit {
                 let error: RuleErrorInfo = RuleErrorInfo::builder()
                     .rule("TextualColumnRule")
                     .unwrap()
-                    .object(format!("{table_name}.{column_name}"))
+                    .object(ObjectRef::column(database, column))
                     .unwrap()
                     .message(format!(
-                        "Textual column '{table_name}.{column_name}' appears in an index but has length limit {limit} which is greater than 255."
+                        "Textual column '{table_name}.{column_name}' appears in the primary key or in a unique index but has length limit {limit} which is greater than {indexed_length_limit}."
                     ))
                     .unwrap()
-                    .resolution(
-                        "Reduce the length limit to 255 or less, or remove the column from the index."
-                            .to_string(),
-                    )
+                    .resolution(format!(
+                        "Reduce the length limit to {indexed_length_limit} or less, or remove the column from the index."
+                    ))
                     .unwrap()
                     .try_into()
                     .unwrap();
@@ -169,17 +256,18 @@ impl<DB: DatabaseLike> TextualColumnRule<DB> {
                     error.into(),
                 ));
             }
-        } else if limit > 8192 {
+        } else if limit > self.length_limit {
+            let length_limit = self.length_limit;
             let error: RuleErrorInfo = RuleErrorInfo::builder()
                 .rule("TextualColumnRule")
                 .unwrap()
-                .object(format!("{table_name}.{column_name}"))
+                .object(ObjectRef::column(database, column))
                 .unwrap()
                 .message(format!(
-                    "Textual column '{table_name}.{column_name}' has length limit {limit} which is greater than 8192 (8K). This column likely stores a document."
+                    "Textual column '{table_name}.{column_name}' has length limit {limit} which is greater than {length_limit}. This column likely stores a document."
                 ))
                 .unwrap()
-                .resolution("If you intend to store large text documents, this might be better suited for a document store or Blob storage. Consider reducing the size if not necessary.".to_string())
+                .resolution("If you intend to store large text documents, mark the column as a document column, or consider a document store or Blob storage. Consider reducing the size if not necessary.".to_string())
                 .unwrap()
                 .try_into()
                 .unwrap();
@@ -206,8 +294,14 @@ impl<DB: DatabaseLike> ColumnRule for TextualColumnRule<DB> {
         }
 
         Self::ensure_not_empty_constraint(database, column)?;
+
+        // Document columns are not required to be bounded.
+        if self.is_document_column(column.table(database).table_name(), column.column_name()) {
+            return Ok(());
+        }
+
         let limit = Self::ensure_length_constraint_exists(database, column)?;
-        Self::ensure_length_limits(database, column, limit)?;
+        self.ensure_length_limits(database, column, limit)?;
 
         Ok(())
     }
@@ -0,0 +1,120 @@
+//! Submodule providing the `TimestampWithTimeZone` constraint, which enforces
+//! that timestamp columns store the time zone.
+
+use sql_traits::traits::{ColumnLike, DatabaseLike, TableLike};
+
+use crate::{
+    error::{ObjectRef, RuleErrorInfo},
+    traits::{ColumnRule, Constrainer, GenericConstrainer},
+};
+
+/// Struct defining a constraint that enforces that timestamp columns are
+/// declared as `TIMESTAMP WITH TIME ZONE` (i.e. `TIMESTAMPTZ`) rather than as
+/// `TIMESTAMP`, whose values are ambiguous as soon as clients run in
+/// different time zones.
+///
+/// # Example
+///
+/// Here follows an example of validating an invalid SQL statement with the
+/// `TimestampWithTimeZone` constraint.
+///
+/// ```rust
+/// use sql_rules::prelude::*;
+/// use sqlparser::dialect::GenericDialect;
+///
+/// let constrainer: GenericConstrainer<ParserDB> = TimestampWithTimeZone::default().into();
+///
+/// let invalid_schema =
+///     ParserDB::parse::<GenericDialect>("CREATE TABLE users (id INT PRIMARY KEY, created_at TIMESTAMP);")
+///         .unwrap();
+/// assert!(constrainer.validate_schema(&invalid_schema).is_err());
+///
+/// let valid_schema = ParserDB::parse::<GenericDialect>(
+///     "CREATE TABLE users (id INT PRIMARY KEY, created_at TIMESTAMP WITH TIME ZONE);",
+/// )
+/// .unwrap();
+/// assert!(constrainer.validate_schema(&valid_schema).is_ok());
+///
+/// // Columns holding local times by design can be exempted
+/// let constrainer: GenericConstrainer<ParserDB> =
+///     TimestampWithTimeZone::default().with_exemption("created_at").into();
+/// assert!(constrainer.validate_schema(&invalid_schema).is_ok());
+/// ```
+pub struct TimestampWithTimeZone<DB> {
+    exemptions: Vec<String>,
+    _phantom: std::marker::PhantomData<DB>,
+}
+
+impl<DB> TimestampWithTimeZone<DB> {
+    /// Adds the name of a column exempted from the rule.
+    #[must_use]
+    pub fn with_exemption(mut self, column_name: impl Into<String>) -> Self {
+        self.exemptions.push(column_name.into());
+        self
+    }
+}
+
+impl<DB> Default for TimestampWithTimeZone<DB> {
+    fn default() -> Self {
+        Self {
+            exemptions: Vec::new(),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<DB: DatabaseLike + 'static> From<TimestampWithTimeZone<DB>> for GenericConstrainer<DB> {
+    fn from(constraint: TimestampWithTimeZone<DB>) -> Self {
+        let mut constrainer = GenericConstrainer::default();
+        constrainer.register_column_rule(Box::new(constraint));
+        constrainer
+    }
+}
+
+impl<DB: DatabaseLike> ColumnRule for TimestampWithTimeZone<DB> {
+    type Database = DB;
+
+    fn validate_column(
+        &self,
+        database: &Self::Database,
+        column: &<Self::Database as DatabaseLike>::Column,
+    ) -> Result<(), crate::error::Error<DB>> {
+        let column_name = column.column_name();
+        if self
+            .exemptions
+            .iter()
+            .any(|exemption| exemption.eq_ignore_ascii_case(column_name))
+        {
+            return Ok(());
+        }
+
+        let data_type = column.normalized_data_type(database).to_lowercase();
+        if !data_type.starts_with("timestamp")
+            || data_type.starts_with("timestamptz")
+            || data_type.contains("with time zone")
+        {
+            return Ok(());
+        }
+
+        let table_name = column.table(database).table_name();
+        let error: RuleErrorInfo = RuleErrorInfo::builder()
+            .rule("TimestampWithTimeZone")
+            .unwrap()
+            .object(ObjectRef::column(database, column))
+            .unwrap()
+            .message(format!(
+                "Column '{table_name}.{column_name}' is a timestamp without time zone, whose values are ambiguous across time zones."
+            ))
+            .unwrap()
+            .resolution(format!(
+                "Declare column '{table_name}.{column_name}' as `TIMESTAMP WITH TIME ZONE` (i.e. `TIMESTAMPTZ`)."
+            ))
+            .unwrap()
+            .try_into()
+            .unwrap();
+        Err(crate::error::Error::Column(
+            Box::new(column.clone()),
+            error.into(),
+        ))
+    }
+}
@@ -0,0 +1,279 @@
+//! Submodule providing the `ConstraintNamingTemplate` rule, which enforces
+//! that the names of foreign keys, check constraints and indices follow
+//! configurable templates.
+
+use sql_traits::traits::{ColumnLike, DatabaseLike, ForeignKeyLike, IndexLike, TableLike};
+
+use crate::{
+    error::{ObjectRef, RuleErrorInfo},
+    rules::named_objects::{
+        TableObject, table_and_foreign_key_constrainer, validate_table_objects,
+    },
+    traits::{ForeignKeyRule, GenericConstrainer, TableRule},
+};
+
+/// Struct defining a rule that enforces that the names of foreign keys, check
+/// constraints and indices follow a template configured per object type.
+///
+/// Templates may use the following placeholders:
+///
+/// 1. `{table}`, replaced by the name of the table the object belongs to.
+/// 2. `{columns}`, replaced by the names of the columns involved in the
+///    object joined by underscores: the host columns of a foreign key, the
+///    columns constrained by a check constraint (in the order the table
+///    declares them) and the indexed columns.
+/// 3. `{referenced_table}`, replaced by the name of the table referenced by a
+///    foreign key.
+///
+/// The default templates are `{table}_{columns}_fkey` for foreign keys,
+/// `{table}_{columns}_check` for check constraints, `{table}_{columns}_key`
+/// for unique constraints and `{table}_{columns}_idx` for the other indices.
+/// They resemble the names PostgreSQL generates, but are applied uniformly:
+/// a check constraint on several columns is expected to list all of them,
+/// and expected names are never truncated. Objects without a name are
+/// ignored, see [`ExplicitlyNamedConstraint`](crate::rules::ExplicitlyNamedConstraint)
+/// to require them to be named.
+///
+/// Primary key constraints, which PostgreSQL names `{table}_pkey`, are not
+/// checked, as `sql-traits` does not expose the name of the constraint of a
+/// primary key.
+///
+/// # Example
+///
+/// ```rust
+/// use sql_rules::prelude::*;
+/// use sqlparser::dialect::GenericDialect;
+///
+/// let constrainer: GenericConstrainer<ParserDB> = ConstraintNamingTemplate::default().into();
+///
+/// let invalid_schema = ParserDB::parse::<GenericDialect>(
+///     "CREATE TABLE authors (id INT PRIMARY KEY);
+///      CREATE TABLE books (id INT PRIMARY KEY, author_id INT, CONSTRAINT fk_author FOREIGN KEY (author_id) REFERENCES authors(id));",
+/// )
+/// .unwrap();
+/// assert!(constrainer.validate_schema(&invalid_schema).is_err());
+///
+/// let invalid_schema = ParserDB::parse::<GenericDialect>(
+///     "CREATE TABLE items (id INT PRIMARY KEY, qty INT, CONSTRAINT positive_qty CHECK (qty > 0));",
+/// )
+/// .unwrap();
+/// assert!(constrainer.validate_schema(&invalid_schema).is_err());
+///
+/// let valid_schema = ParserDB::parse::<GenericDialect>(
+///     "CREATE TABLE authors (id INT PRIMARY KEY);
+///      CREATE TABLE books (id INT PRIMARY KEY, author_id INT, qty INT, CONSTRAINT books_author_id_fkey FOREIGN KEY (author_id) REFERENCES authors(id), CONSTRAINT books_qty_check CHECK (qty > 0));",
+/// )
+/// .unwrap();
+/// assert!(constrainer.validate_schema(&valid_schema).is_ok());
+///
+/// // A check constraint on several columns lists them in table order.
+/// let valid_schema = ParserDB::parse::<GenericDialect>(
+///     "CREATE TABLE ranges (id INT PRIMARY KEY, low INT, high INT, CONSTRAINT ranges_low_high_check CHECK (high > low));",
+/// )
+/// .unwrap();
+/// assert!(constrainer.validate_schema(&valid_schema).is_ok());
+///
+/// let invalid_schema = ParserDB::parse::<GenericDialect>(
+///     "CREATE TABLE ranges (id INT PRIMARY KEY, low INT, high INT, CONSTRAINT ranges_check CHECK (high > low));",
+/// )
+/// .unwrap();
+/// assert!(constrainer.validate_schema(&invalid_schema).is_err());
+///
+/// // Custom templates
+/// let constrainer: GenericConstrainer<ParserDB> = ConstraintNamingTemplate::default()
+///     .with_foreign_key_template("fk_{table}_{referenced_table}")
+///     .into();
+/// let valid_schema = ParserDB::parse::<GenericDialect>(
+///     "CREATE TABLE authors (id INT PRIMARY KEY);
+///      CREATE TABLE books (id INT PRIMARY KEY, author_id INT, CONSTRAINT fk_books_authors FOREIGN KEY (author_id) REFERENCES authors(id));",
+/// )
+/// .unwrap();
+/// assert!(constrainer.validate_schema(&valid_schema).is_ok());
+/// ```
+pub struct ConstraintNamingTemplate<DB> {
+    foreign_key_template: String,
+    check_constraint_template: String,
+    unique_index_template: String,
+    index_template: String,
+    _phantom: std::marker::PhantomData<DB>,
+}
+
+impl<DB> ConstraintNamingTemplate<DB> {
+    /// Sets the template of the names of foreign keys.
+    #[must_use]
+    pub fn with_foreign_key_template(mut self, template: impl Into<String>) -> Self {
+        self.foreign_key_template = template.into();
+        self
+    }
+
+    /// Sets the template of the names of check constraints.
+    #[must_use]
+    pub fn with_check_constraint_template(mut self, template: impl Into<String>) -> Self {
+        self.check_constraint_template = template.into();
+        self
+    }
+
+    /// Sets the template of the names of unique constraints.
+    #[must_use]
+    pub fn with_unique_index_template(mut self, template: impl Into<String>) -> Self {
+        self.unique_index_template = template.into();
+        self
+    }
+
+    /// Sets the template of the names of the indices which are not unique.
+    #[must_use]
+    pub fn with_index_template(mut self, template: impl Into<String>) -> Self {
+        self.index_template = template.into();
+        self
+    }
+
+    /// Returns the error for an object of the provided kind whose name does
+    /// not follow the provided template, if any.
+    fn error<'a>(
+        kind: &str,
+        name: &str,
+        template: &str,
+        table_name: &str,
+        columns: impl IntoIterator<Item = &'a str>,
+        referenced_table_name: Option<&str>,
+        object: impl FnOnce() -> ObjectRef,
+    ) -> Option<RuleErrorInfo> {
+        let columns = columns.into_iter().collect::<Vec<_>>().join("_");
+        let mut expected = template
+            .replace("{table}", table_name)
+            .replace("{columns}", &columns);
+        if let Some(referenced_table_name) = referenced_table_name {
+            expected = expected.replace("{referenced_table}", referenced_table_name);
+        }
+        if name.eq_ignore_ascii_case(&expected) {
+            return None;
+        }
+        let subject = kind.to_lowercase();
+        Some(
+            RuleErrorInfo::builder()
+                .rule("ConstraintNamingTemplate")
+                .unwrap()
+                .object(object())
+                .unwrap()
+                .message(format!(
+                    "{kind} name '{name}' on table '{table_name}' does not follow the template '{template}'."
+                ))
+                .unwrap()
+                .resolution(format!("Rename the {subject} '{name}' to '{expected}'."))
+                .unwrap()
+                .try_into()
+                .unwrap(),
+        )
+    }
+}
+
+impl<DB> Default for ConstraintNamingTemplate<DB> {
+    fn default() -> Self {
+        Self {
+            foreign_key_template: "{table}_{columns}_fkey".to_string(),
+            check_constraint_template: "{table}_{columns}_check".to_string(),
+            unique_index_template: "{table}_{columns}_key".to_string(),
+            index_template: "{table}_{columns}_idx".to_string(),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<DB> Clone for ConstraintNamingTemplate<DB> {
+    fn clone(&self) -> Self {
+        Self {
+            foreign_key_template: self.foreign_key_template.clone(),
+            check_constraint_template: self.check_constraint_template.clone(),
+            unique_index_template: self.unique_index_template.clone(),
+            index_template: self.index_template.clone(),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<DB: DatabaseLike + 'static> From<ConstraintNamingTemplate<DB>> for GenericConstrainer<DB> {
+    fn from(rule: ConstraintNamingTemplate<DB>) -> Self {
+        table_and_foreign_key_constrainer(rule)
+    }
+}
+
+impl<DB: DatabaseLike> TableRule for ConstraintNamingTemplate<DB> {
+    type Database = DB;
+
+    fn validate_table(
+        &self,
+        database: &Self::Database,
+        table: &<Self::Database as DatabaseLike>::Table,
+    ) -> Result<(), crate::error::Error<DB>> {
+        let table_name = table.table_name();
+        validate_table_objects(database, table, |object| {
+            let name = object.name()?;
+            let (template, columns) = match &object {
+                TableObject::Table(_) => return None,
+                TableObject::CheckConstraint(_, cc) => (
+                    &self.check_constraint_template,
+                    table
+                        .columns(database)
+                        .filter(|column| {
+                            column.check_constraints(database).any(|other| other == *cc)
+                        })
+                        .map(ColumnLike::column_name)
+                        .collect::<Vec<_>>(),
+                ),
+                TableObject::Index(_, index) => (
+                    &self.index_template,
+                    index
+                        .columns(database)
+                        .map(ColumnLike::column_name)
+                        .collect(),
+                ),
+                TableObject::UniqueIndex(_, index) => (
+                    &self.unique_index_template,
+                    index
+                        .columns(database)
+                        .map(ColumnLike::column_name)
+                        .collect(),
+                ),
+            };
+            Self::error(
+                object.kind(),
+                name,
+                template,
+                table_name,
+                columns,
+                None,
+                || object.object_ref(database),
+            )
+        })
+    }
+}
+
+impl<DB: DatabaseLike> ForeignKeyRule for ConstraintNamingTemplate<DB> {
+    type Database = DB;
+
+    fn validate_foreign_key(
+        &self,
+        database: &Self::Database,
+        foreign_key: &<Self::Database as DatabaseLike>::ForeignKey,
+    ) -> Result<(), crate::error::Error<DB>> {
+        if let Some(name) = foreign_key.foreign_key_name()
+            && let Some(error) = Self::error(
+                "Foreign key",
+                name,
+                &self.foreign_key_template,
+                foreign_key.host_table(database).table_name(),
+                foreign_key
+                    .host_columns(database)
+                    .map(ColumnLike::column_name),
+                Some(foreign_key.referenced_table(database).table_name()),
+                || ObjectRef::foreign_key(database, foreign_key),
+            )
+        {
+            return Err(crate::error::Error::ForeignKey(
+                Box::new(foreign_key.clone()),
+                error.into(),
+            ));
+        }
+        Ok(())
+    }
+}
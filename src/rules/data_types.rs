@@ -0,0 +1,73 @@
+//! Submodule providing the families of data types shared by the column rules
+//! which only apply to some data types, such as integer primary keys or
+//! timestamp columns.
+
+/// The integer types narrower than 64 bits, including their auto-increment
+/// `SERIAL` variants.
+pub(crate) const NARROW_INTEGER_DATA_TYPES: &[&str] = &[
+    "tinyint",
+    "smallint",
+    "mediumint",
+    "int",
+    "integer",
+    "int2",
+    "int4",
+    "smallserial",
+    "serial",
+    "serial2",
+    "serial4",
+];
+
+/// The 64-bit integer types, including their auto-increment `SERIAL`
+/// variants.
+pub(crate) const WIDE_INTEGER_DATA_TYPES: &[&str] = &["bigint", "int8", "bigserial", "serial8"];
+
+/// The exact decimal types.
+pub(crate) const DECIMAL_DATA_TYPES: &[&str] = &["numeric", "decimal"];
+
+/// The floating point types.
+pub(crate) const FLOATING_POINT_DATA_TYPES: &[&str] = &[
+    "real",
+    "float",
+    "float4",
+    "float8",
+    "double",
+    "binary_float",
+    "binary_double",
+];
+
+/// The types of dates and points in time.
+pub(crate) const TIME_DATA_TYPES: &[&str] = &[
+    "date",
+    "datetime",
+    "datetime2",
+    "datetimeoffset",
+    "smalldatetime",
+    "timestamp",
+    "timestamptz",
+];
+
+/// The blank-padded fixed length character types.
+pub(crate) const FIXED_LENGTH_CHAR_DATA_TYPES: &[&str] = &["char", "character", "nchar", "bpchar"];
+
+/// Returns the base type of the provided lowercase data type, i.e. its first
+/// word without any parameter: `varchar` for `varchar(255)` and `double` for
+/// `double precision`.
+pub(crate) fn base_type(data_type: &str) -> &str {
+    data_type.split(['(', ' ']).next().unwrap_or_default()
+}
+
+/// Returns whether the provided lowercase data type is an integer type.
+pub(crate) fn is_integer(data_type: &str) -> bool {
+    let base_type = base_type(data_type);
+    NARROW_INTEGER_DATA_TYPES.contains(&base_type) || WIDE_INTEGER_DATA_TYPES.contains(&base_type)
+}
+
+/// Returns whether the provided lowercase data type is a numeric type, i.e.
+/// an integer, exact decimal or floating point type.
+pub(crate) fn is_numeric(data_type: &str) -> bool {
+    let base_type = base_type(data_type);
+    is_integer(base_type)
+        || DECIMAL_DATA_TYPES.contains(&base_type)
+        || FLOATING_POINT_DATA_TYPES.contains(&base_type)
+}
@@ -0,0 +1,5 @@
+//! Submodule providing constraint structs that are applied once to the whole
+//! database.
+
+mod consistent_column_type;
+pub use consistent_column_type::ConsistentColumnType;
@@ -0,0 +1,215 @@
+//! Submodule providing the `ConsistentColumnType` constraint, which enforces
+//! that columns with the same name have the same type across tables.
+
+use std::collections::BTreeMap;
+use std::fmt::Display;
+
+use sql_traits::traits::{ColumnLike, DatabaseLike, TableLike};
+
+use crate::{
+    error::{ObjectRef, RuleErrorInfo},
+    rules::TextualColumnRule,
+    traits::{Constrainer, DatabaseRule, GenericConstrainer},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+/// The properties of a column which must match across same-named columns.
+struct ColumnSignature {
+    /// The normalized data type of the column, lowercased.
+    data_type: String,
+    /// Whether the column is nullable.
+    nullable: bool,
+    /// The upper bound on the length of the column, if textual.
+    length_bound: Option<usize>,
+}
+
+impl ColumnSignature {
+    /// Returns the signature of the provided column.
+    fn of<DB: DatabaseLike>(database: &DB, column: &DB::Column) -> Self {
+        Self {
+            data_type: column.normalized_data_type(database).to_lowercase(),
+            nullable: column.is_nullable(database),
+            length_bound: if column.is_textual(database) {
+                TextualColumnRule::<DB>::length_upper_bound(database, column)
+            } else {
+                None
+            },
+        }
+    }
+}
+
+impl Display for ColumnSignature {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.data_type.to_uppercase())?;
+        if !self.nullable {
+            write!(f, " NOT NULL")?;
+        }
+        if let Some(length_bound) = self.length_bound {
+            write!(f, " with length at most {length_bound}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Struct defining a constraint that enforces that columns with the same name
+/// have the same normalized data type, nullability and textual length bound
+/// (from check constraints or from the declared type, as in
+/// [`TextualColumnRule`]) across all tables.
+///
+/// The columns are grouped by name in a single pass over the database, and
+/// each inconsistent group is reported as a whole, naming the most common
+/// signature of the group as the suggested fix for the other columns.
+///
+/// # Example
+///
+/// Here follows an example of validating an invalid SQL statement with the
+/// `ConsistentColumnType` constraint.
+///
+/// ```rust
+/// use sql_rules::prelude::*;
+/// use sqlparser::dialect::GenericDialect;
+///
+/// let constrainer: GenericConstrainer<ParserDB> = ConsistentColumnType::default().into();
+///
+/// let invalid_schema = ParserDB::parse::<GenericDialect>(
+///     "
+/// CREATE TABLE users (id INT PRIMARY KEY, email VARCHAR(320) NOT NULL);
+/// CREATE TABLE invitations (id INT PRIMARY KEY, email VARCHAR(320) NOT NULL);
+/// CREATE TABLE newsletters (id INT PRIMARY KEY, email TEXT);
+/// ",
+/// )
+/// .unwrap();
+/// assert!(constrainer.validate_schema(&invalid_schema).is_err());
+///
+/// let valid_schema = ParserDB::parse::<GenericDialect>(
+///     "
+/// CREATE TABLE users (id INT PRIMARY KEY, email VARCHAR(320) NOT NULL);
+/// CREATE TABLE invitations (id INT PRIMARY KEY, email VARCHAR(320) NOT NULL);
+/// ",
+/// )
+/// .unwrap();
+/// assert!(constrainer.validate_schema(&valid_schema).is_ok());
+///
+/// // Columns can be excluded from the comparison
+/// let constrainer: GenericConstrainer<ParserDB> =
+///     ConsistentColumnType::default().with_ignored_column("email").into();
+/// assert!(constrainer.validate_schema(&invalid_schema).is_ok());
+/// ```
+pub struct ConsistentColumnType<DB> {
+    ignored_columns: Vec<String>,
+    _phantom: std::marker::PhantomData<DB>,
+}
+
+impl<DB> ConsistentColumnType<DB> {
+    /// Excludes columns with the provided name from the comparison.
+    #[must_use]
+    pub fn with_ignored_column(mut self, column_name: impl Into<String>) -> Self {
+        self.ignored_columns.push(column_name.into());
+        self
+    }
+}
+
+impl<DB> Default for ConsistentColumnType<DB> {
+    fn default() -> Self {
+        Self {
+            ignored_columns: Vec::new(),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<DB: DatabaseLike + 'static> From<ConsistentColumnType<DB>> for GenericConstrainer<DB> {
+    fn from(constraint: ConsistentColumnType<DB>) -> Self {
+        let mut constrainer = GenericConstrainer::default();
+        constrainer.register_database_rule(Box::new(constraint));
+        constrainer
+    }
+}
+
+impl<DB: DatabaseLike> DatabaseRule for ConsistentColumnType<DB> {
+    type Database = DB;
+
+    fn validate_database(&self, database: &Self::Database) -> Result<(), crate::error::Error<DB>> {
+        // The columns of the database, grouped by name and then by signature.
+        let mut groups: BTreeMap<&str, BTreeMap<ColumnSignature, Vec<&DB::Column>>> =
+            BTreeMap::new();
+        for column in database.tables().flat_map(|table| table.columns(database)) {
+            let column_name = column.column_name();
+            if self
+                .ignored_columns
+                .iter()
+                .any(|ignored| ignored.eq_ignore_ascii_case(column_name))
+            {
+                continue;
+            }
+            groups
+                .entry(column_name)
+                .or_default()
+                .entry(ColumnSignature::of(database, column))
+                .or_default()
+                .push(column);
+        }
+
+        for (column_name, signatures) in groups {
+            if signatures.len() < 2 {
+                continue;
+            }
+            let Some((majority, majority_columns)) =
+                signatures
+                    .iter()
+                    .max_by(|(left, left_columns), (right, right_columns)| {
+                        left_columns
+                            .len()
+                            .cmp(&right_columns.len())
+                            .then(right.cmp(left))
+                    })
+            else {
+                continue;
+            };
+            let table_names = |columns: &[&DB::Column]| {
+                columns
+                    .iter()
+                    .map(|column| column.table(database).table_name())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            };
+            let minority_columns = signatures
+                .iter()
+                .filter(|(signature, _)| *signature != majority)
+                .flat_map(|(_, columns)| columns.iter().copied())
+                .collect::<Vec<_>>();
+            let Some(first_minority_column) = minority_columns.first() else {
+                continue;
+            };
+
+            let group_size: usize = signatures.values().map(Vec::len).sum();
+            let variants = signatures
+                .iter()
+                .map(|(signature, columns)| format!("{signature} in {}", table_names(columns)))
+                .collect::<Vec<_>>()
+                .join("; ");
+            let error: RuleErrorInfo = RuleErrorInfo::builder()
+                .rule("ConsistentColumnType")
+                .unwrap()
+                .object(ObjectRef::column(database, first_minority_column))
+                .unwrap()
+                .message(format!(
+                    "The {group_size} columns named '{column_name}' are inconsistent: {variants}."
+                ))
+                .unwrap()
+                .resolution(format!(
+                    "Change the columns named '{column_name}' in {} to {majority}, as in {} of the {group_size} columns, or rename them if they hold a different kind of value.",
+                    table_names(&minority_columns),
+                    majority_columns.len()
+                ))
+                .unwrap()
+                .try_into()
+                .unwrap();
+            return Err(crate::error::Error::Column(
+                Box::new((*first_minority_column).clone()),
+                error.into(),
+            ));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,152 @@
+//! Submodule providing the `ExplicitlyNamedConstraint` rule, which enforces
+//! that foreign keys, check constraints and unique constraints are explicitly
+//! named.
+
+use sql_traits::traits::{
+    CheckConstraintLike, ColumnLike, DatabaseLike, ForeignKeyLike, IndexLike, TableLike,
+};
+
+use crate::{
+    error::{ObjectRef, RuleErrorInfo},
+    rules::named_objects::{
+        TableObject, table_and_foreign_key_constrainer, validate_table_objects,
+    },
+    traits::{ForeignKeyRule, GenericConstrainer, TableRule},
+};
+
+/// Struct defining a rule that enforces that foreign keys, check constraints
+/// and unique constraints are explicitly named, with a `CONSTRAINT <name>`
+/// clause, instead of relying on the names generated by the database.
+///
+/// Generated names differ across databases and versions, which makes
+/// migrations dropping or altering the constraints brittle, and unnamed
+/// constraints are hard to identify in error messages. The rule pairs well
+/// with [`ConstraintNamingTemplate`](crate::rules::ConstraintNamingTemplate),
+/// which checks the names themselves.
+///
+/// Primary key constraints are not covered: `sql-traits` exposes the columns
+/// of a primary key but not the name of its constraint, so an unnamed
+/// `PRIMARY KEY` cannot be told apart from a named one.
+///
+/// # Example
+///
+/// ```rust
+/// use sql_rules::prelude::*;
+/// use sqlparser::dialect::GenericDialect;
+///
+/// let constrainer: GenericConstrainer<ParserDB> = ExplicitlyNamedConstraint::default().into();
+///
+/// let invalid_schema = ParserDB::parse::<GenericDialect>(
+///     "CREATE TABLE authors (id INT PRIMARY KEY);
+///      CREATE TABLE books (id INT PRIMARY KEY, author_id INT REFERENCES authors(id));",
+/// )
+/// .unwrap();
+/// assert!(constrainer.validate_schema(&invalid_schema).is_err());
+///
+/// let invalid_schema = ParserDB::parse::<GenericDialect>(
+///     "CREATE TABLE items (id INT PRIMARY KEY, qty INT CHECK (qty > 0));",
+/// )
+/// .unwrap();
+/// assert!(constrainer.validate_schema(&invalid_schema).is_err());
+///
+/// let valid_schema = ParserDB::parse::<GenericDialect>(
+///     "CREATE TABLE authors (id INT PRIMARY KEY);
+///      CREATE TABLE books (id INT PRIMARY KEY, author_id INT, qty INT, CONSTRAINT books_author_id_fkey FOREIGN KEY (author_id) REFERENCES authors(id), CONSTRAINT books_qty_check CHECK (qty > 0));",
+/// )
+/// .unwrap();
+/// assert!(constrainer.validate_schema(&valid_schema).is_ok());
+/// ```
+pub struct ExplicitlyNamedConstraint<DB>(std::marker::PhantomData<DB>);
+
+impl<DB> Default for ExplicitlyNamedConstraint<DB> {
+    fn default() -> Self {
+        Self(std::marker::PhantomData)
+    }
+}
+
+impl<DB> Clone for ExplicitlyNamedConstraint<DB> {
+    fn clone(&self) -> Self {
+        Self::default()
+    }
+}
+
+impl<DB: DatabaseLike + 'static> From<ExplicitlyNamedConstraint<DB>> for GenericConstrainer<DB> {
+    fn from(rule: ExplicitlyNamedConstraint<DB>) -> Self {
+        table_and_foreign_key_constrainer(rule)
+    }
+}
+
+/// Returns the error for an unnamed constraint with the provided description.
+fn error(description: &str, object: ObjectRef) -> RuleErrorInfo {
+    RuleErrorInfo::builder()
+        .rule("ExplicitlyNamedConstraint")
+        .unwrap()
+        .object(object)
+        .unwrap()
+        .message(format!("{description} is not explicitly named."))
+        .unwrap()
+        .resolution("Name the constraint with a `CONSTRAINT <name>` clause.".to_string())
+        .unwrap()
+        .try_into()
+        .unwrap()
+}
+
+impl<DB: DatabaseLike> TableRule for ExplicitlyNamedConstraint<DB> {
+    type Database = DB;
+
+    fn validate_table(
+        &self,
+        database: &Self::Database,
+        table: &<Self::Database as DatabaseLike>::Table,
+    ) -> Result<(), crate::error::Error<DB>> {
+        let table_name = table.table_name();
+        validate_table_objects(database, table, |object| {
+            if object.name().is_some() {
+                return None;
+            }
+            let description = match &object {
+                TableObject::CheckConstraint(_, cc) => format!(
+                    "Check constraint `CHECK ({})` on table '{table_name}'",
+                    cc.expression(database)
+                ),
+                TableObject::UniqueIndex(_, index) => format!(
+                    "Unique constraint `UNIQUE ({})` on table '{table_name}'",
+                    index.expression(database)
+                ),
+                TableObject::Table(_) | TableObject::Index(..) => return None,
+            };
+            Some(error(&description, object.object_ref(database)))
+        })
+    }
+}
+
+impl<DB: DatabaseLike> ForeignKeyRule for ExplicitlyNamedConstraint<DB> {
+    type Database = DB;
+
+    fn validate_foreign_key(
+        &self,
+        database: &Self::Database,
+        foreign_key: &<Self::Database as DatabaseLike>::ForeignKey,
+    ) -> Result<(), crate::error::Error<DB>> {
+        if foreign_key.foreign_key_name().is_some() {
+            return Ok(());
+        }
+        let host_table_name = foreign_key.host_table(database).table_name();
+        let referenced_table_name = foreign_key.referenced_table(database).table_name();
+        let host_columns = foreign_key
+            .host_columns(database)
+            .map(ColumnLike::column_name)
+            .collect::<Vec<_>>()
+            .join(", ");
+        let error = error(
+            &format!(
+                "Foreign key from '{host_table_name}' ({host_columns}) to '{referenced_table_name}'"
+            ),
+            ObjectRef::foreign_key(database, foreign_key),
+        );
+        Err(crate::error::Error::ForeignKey(
+            Box::new(foreign_key.clone()),
+            error.into(),
+        ))
+    }
+}
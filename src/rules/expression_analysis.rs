@@ -0,0 +1,614 @@
+//! Submodule providing helpers to inspect the AST of check constraint and
+//! index expressions, shared by the rules which need more than a textual
+//! match.
+
+use std::{
+    cmp::Ordering,
+    collections::{BTreeMap, BTreeSet},
+};
+
+use sqlparser::ast::{
+    BinaryOperator, Expr, Function, FunctionArg, FunctionArgExpr, FunctionArguments, Ident,
+    UnaryOperator, Value, ValueWithSpan,
+};
+
+/// Names of the functions (and niladic keywords) evaluating to the current
+/// date or time, lowercased.
+const CURRENT_TIME_FUNCTIONS: &[&str] = &[
+    "now",
+    "current_timestamp",
+    "current_date",
+    "localtimestamp",
+    "transaction_timestamp",
+    "statement_timestamp",
+    "clock_timestamp",
+    "getdate",
+    "getutcdate",
+    "sysdate",
+    "sysdatetime",
+    "sysutcdatetime",
+    "utc_timestamp",
+];
+
+/// Names of functions which are aliases of one another, lowercased, with the
+/// name they are normalized to.
+const FUNCTION_ALIASES: &[(&str, &str)] = &[
+    ("char_length", "length"),
+    ("character_length", "length"),
+    ("len", "length"),
+    ("lcase", "lower"),
+    ("ucase", "upper"),
+    ("current_timestamp", "now"),
+    ("transaction_timestamp", "now"),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+/// Comparison operators, independent of the dialect-specific operator enum.
+pub(crate) enum Comparison {
+    /// `<`
+    Lt,
+    /// `<=`
+    LtEq,
+    /// `=`
+    Eq,
+    /// `<>` or `!=`
+    NotEq,
+    /// `>=`
+    GtEq,
+    /// `>`
+    Gt,
+}
+
+impl Comparison {
+    /// Returns the comparison corresponding to the provided operator, if any.
+    pub(crate) fn from_operator(operator: &BinaryOperator) -> Option<Self> {
+        match operator {
+            BinaryOperator::Lt => Some(Comparison::Lt),
+            BinaryOperator::LtEq => Some(Comparison::LtEq),
+            BinaryOperator::Eq => Some(Comparison::Eq),
+            BinaryOperator::NotEq => Some(Comparison::NotEq),
+            BinaryOperator::GtEq => Some(Comparison::GtEq),
+            BinaryOperator::Gt => Some(Comparison::Gt),
+            _ => None,
+        }
+    }
+
+    /// Returns the comparison obtained by swapping the operands, i.e. `a < b`
+    /// becomes `b > a`.
+    pub(crate) fn flipped(self) -> Self {
+        match self {
+            Comparison::Lt => Comparison::Gt,
+            Comparison::LtEq => Comparison::GtEq,
+            Comparison::GtEq => Comparison::LtEq,
+            Comparison::Gt => Comparison::Lt,
+            Comparison::Eq | Comparison::NotEq => self,
+        }
+    }
+
+    /// Returns the SQL symbol of the comparison.
+    pub(crate) fn symbol(self) -> &'static str {
+        match self {
+            Comparison::Lt => "<",
+            Comparison::LtEq => "<=",
+            Comparison::Eq => "=",
+            Comparison::NotEq => "<>",
+            Comparison::GtEq => ">=",
+            Comparison::Gt => ">",
+        }
+    }
+}
+
+/// Returns the provided expression stripped of any wrapping parentheses.
+pub(crate) fn strip_parentheses(mut expr: &Expr) -> &Expr {
+    while let Expr::Nested(inner) = expr {
+        expr = inner;
+    }
+    expr
+}
+
+/// Returns the provided expression stripped of any wrapping parentheses and
+/// casts, as in `(created_at::DATE)`.
+pub(crate) fn strip_parentheses_and_casts(mut expr: &Expr) -> &Expr {
+    loop {
+        match expr {
+            Expr::Nested(inner) | Expr::Cast { expr: inner, .. } => expr = inner,
+            _ => return expr,
+        }
+    }
+}
+
+/// Returns the operands of the top-level conjunction of the provided
+/// expression, i.e. `a AND (b AND c)` yields `[a, b, c]`.
+pub(crate) fn conjuncts(expr: &Expr) -> Vec<&Expr> {
+    match strip_parentheses(expr) {
+        Expr::BinaryOp {
+            left,
+            op: BinaryOperator::And,
+            right,
+        } => {
+            let mut operands = conjuncts(left);
+            operands.extend(conjuncts(right));
+            operands
+        }
+        other => vec![other],
+    }
+}
+
+/// Returns whether the provided expression is a reference to the column with
+/// the provided name, possibly qualified, parenthesized or cast.
+pub(crate) fn is_column_reference(expr: &Expr, column_name: &str) -> bool {
+    match strip_parentheses_and_casts(expr) {
+        Expr::Identifier(ident) => ident.value.eq_ignore_ascii_case(column_name),
+        Expr::CompoundIdentifier(idents) => idents
+            .last()
+            .is_some_and(|ident| ident.value.eq_ignore_ascii_case(column_name)),
+        _ => false,
+    }
+}
+
+/// If the provided expression compares the column with the provided name to
+/// another expression, returns the comparison oriented so that the column is
+/// on the left-hand side, together with the other operand.
+pub(crate) fn column_comparison<'expr>(
+    expr: &'expr Expr,
+    column_name: &str,
+) -> Option<(Comparison, &'expr Expr)> {
+    let Expr::BinaryOp { left, op, right } = strip_parentheses(expr) else {
+        return None;
+    };
+    let comparison = Comparison::from_operator(op)?;
+    if is_column_reference(left, column_name) {
+        Some((comparison, right))
+    } else if is_column_reference(right, column_name) {
+        Some((comparison.flipped(), left))
+    } else {
+        None
+    }
+}
+
+/// Returns the lowercased name of the function called by the provided
+/// expression, including niladic keywords such as `CURRENT_DATE`.
+pub(crate) fn function_name(expr: &Expr) -> Option<String> {
+    match strip_parentheses_and_casts(expr) {
+        Expr::Function(Function { name, .. }) => Some(name.to_string().to_ascii_lowercase()),
+        Expr::Identifier(ident) if ident.quote_style.is_none() => {
+            Some(ident.value.to_ascii_lowercase())
+        }
+        _ => None,
+    }
+}
+
+/// Returns whether the provided expression evaluates to the current date or
+/// time, as `NOW()`, `CURRENT_TIMESTAMP` or `CURRENT_DATE` do.
+pub(crate) fn is_current_time(expr: &Expr) -> bool {
+    function_name(expr).is_some_and(|name| CURRENT_TIME_FUNCTIONS.contains(&name.as_str()))
+}
+
+/// Returns the elements of the provided index expression, i.e. the operands
+/// of a tuple such as `(a, b)`, or the expression itself.
+pub(crate) fn index_elements(expr: &Expr) -> Vec<&Expr> {
+    match strip_parentheses(expr) {
+        Expr::Tuple(elements) => elements.iter().map(strip_parentheses).collect(),
+        other => vec![other],
+    }
+}
+
+/// Returns a key identifying the provided expression regardless of its
+/// formatting, of the case of its unquoted identifiers and of the
+/// qualification of column references: `users.email`, `EMAIL` and `email`
+/// share the same key, as do `lower( email )` and `LOWER(Email)`. Quoted
+/// identifiers are case-sensitive and keep their case, so `"Email"` has a
+/// key distinct from `email`, while `"email"` shares it.
+pub(crate) fn normalized_key(expr: &Expr) -> String {
+    match strip_parentheses(expr) {
+        Expr::Identifier(ident) => identifier_key(ident),
+        Expr::CompoundIdentifier(idents) => idents.last().map(identifier_key).unwrap_or_default(),
+        other => {
+            // The display of the AST is canonical up to quoting and case:
+            // quotes are dropped, and so is the case outside of string
+            // literals and quoted identifiers.
+            let mut in_literal = false;
+            let mut in_quoted_identifier = false;
+            other
+                .to_string()
+                .chars()
+                .filter_map(|character| match character {
+                    '\'' if !in_quoted_identifier => {
+                        in_literal = !in_literal;
+                        Some(character)
+                    }
+                    '"' | '`' if !in_literal => {
+                        in_quoted_identifier = !in_quoted_identifier;
+                        None
+                    }
+                    _ if in_literal || in_quoted_identifier => Some(character),
+                    _ => Some(character.to_ascii_lowercase()),
+                })
+                .collect()
+        }
+    }
+}
+
+/// Returns the key of the provided identifier: its value, lowercased unless
+/// it is quoted.
+fn identifier_key(ident: &Ident) -> String {
+    if ident.quote_style.is_none() {
+        ident.value.to_lowercase()
+    } else {
+        ident.value.clone()
+    }
+}
+
+/// Returns the canonical forms of the operands of the top-level chain of the
+/// provided logical operator, where a `BETWEEN` counts as the conjunction of
+/// its two comparisons.
+fn canonical_operands(expr: &Expr, operator: &BinaryOperator) -> Vec<String> {
+    match strip_parentheses(expr) {
+        Expr::BinaryOp { left, op, right } if op == operator => {
+            let mut operands = canonical_operands(left, operator);
+            operands.extend(canonical_operands(right, operator));
+            operands
+        }
+        Expr::Between {
+            expr,
+            negated: false,
+            low,
+            high,
+        } if *operator == BinaryOperator::And => {
+            let expr = canonical_form(expr);
+            vec![
+                format!("({} <= {expr})", canonical_form(low)),
+                format!("({expr} <= {})", canonical_form(high)),
+            ]
+        }
+        other => vec![canonical_form(other)],
+    }
+}
+
+/// Returns the canonical form of the top-level chain of the provided logical
+/// operator, with its operands ordered and deduplicated.
+fn canonical_chain(expr: &Expr, operator: &BinaryOperator) -> String {
+    let mut operands = canonical_operands(expr, operator);
+    operands.sort_unstable();
+    operands.dedup();
+    format!("({})", operands.join(&format!(" {operator} ")))
+}
+
+/// Returns a canonical form of the provided check expression, so that
+/// semantically equivalent expressions written differently share it.
+///
+/// The canonical form ignores parentheses, formatting, identifier quoting and
+/// case, orders the operands of commutative operators, and of `AND`/`OR`
+/// chains, writes `a > b` as `b < a` (and `a >= b` as `b <= a`), expands
+/// `BETWEEN` into two comparisons, orders `IN` lists and replaces function
+/// aliases such as `char_length` with a single name.
+pub(crate) fn canonical_form(expr: &Expr) -> String {
+    match strip_parentheses(expr) {
+        Expr::BinaryOp {
+            op: operator @ (BinaryOperator::And | BinaryOperator::Or),
+            ..
+        } => canonical_chain(expr, operator),
+        Expr::Between { negated: false, .. } => canonical_chain(expr, &BinaryOperator::And),
+        Expr::BinaryOp { left, op, right } => {
+            let (left, right) = (canonical_form(left), canonical_form(right));
+            if let Some(comparison) = Comparison::from_operator(op) {
+                let (comparison, left, right) = match comparison {
+                    Comparison::Gt | Comparison::GtEq => (comparison.flipped(), right, left),
+                    Comparison::Eq | Comparison::NotEq if right < left => (comparison, right, left),
+                    _ => (comparison, left, right),
+                };
+                format!("({left} {} {right})", comparison.symbol())
+            } else if matches!(
+                op,
+                BinaryOperator::Plus
+                    | BinaryOperator::Multiply
+                    | BinaryOperator::BitwiseAnd
+                    | BinaryOperator::BitwiseOr
+                    | BinaryOperator::BitwiseXor
+            ) && right < left
+            {
+                format!("({right} {op} {left})")
+            } else {
+                format!("({left} {op} {right})")
+            }
+        }
+        Expr::UnaryOp { op, expr } => format!("({op} {})", canonical_form(expr)),
+        Expr::IsNull(expr) => format!("({} IS NULL)", canonical_form(expr)),
+        Expr::IsNotNull(expr) => format!("({} IS NOT NULL)", canonical_form(expr)),
+        Expr::InList {
+            expr,
+            list,
+            negated,
+        } => {
+            let mut items = list.iter().map(canonical_form).collect::<Vec<_>>();
+            items.sort_unstable();
+            items.dedup();
+            let negation = if *negated { "NOT " } else { "" };
+            format!(
+                "({} {negation}IN ({}))",
+                canonical_form(expr),
+                items.join(", ")
+            )
+        }
+        Expr::Function(Function { name, args, .. }) => {
+            let name = name.to_string().to_ascii_lowercase();
+            let name = FUNCTION_ALIASES
+                .iter()
+                .find(|(alias, _)| *alias == name)
+                .map_or(name.as_str(), |(_, canonical_name)| canonical_name);
+            let arguments = match args {
+                FunctionArguments::List(list) => list
+                    .args
+                    .iter()
+                    .map(|argument| match argument {
+                        FunctionArg::Unnamed(FunctionArgExpr::Expr(expr)) => canonical_form(expr),
+                        other => other.to_string().to_ascii_lowercase(),
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                _ => String::new(),
+            };
+            format!("{name}({arguments})")
+        }
+        other => normalized_key(other),
+    }
+}
+
+/// Returns the value of the provided expression if it is a numeric literal,
+/// possibly negated, parenthesized or cast.
+pub(crate) fn numeric_literal(expr: &Expr) -> Option<f64> {
+    match strip_parentheses_and_casts(expr) {
+        Expr::Value(ValueWithSpan {
+            value: Value::Number(number, _),
+            ..
+        }) => number.parse().ok(),
+        Expr::UnaryOp {
+            op: UnaryOperator::Minus,
+            expr,
+        } => numeric_literal(expr).map(|value| -value),
+        Expr::UnaryOp {
+            op: UnaryOperator::Plus,
+            expr,
+        } => numeric_literal(expr),
+        _ => None,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// An endpoint of an [`Interval`].
+pub(crate) struct Bound {
+    /// The value of the endpoint.
+    value: f64,
+    /// Whether the value itself belongs to the interval.
+    inclusive: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+/// An interval of numeric values, possibly unbounded on either side.
+pub(crate) struct Interval {
+    /// The lower endpoint, if any.
+    lower: Option<Bound>,
+    /// The upper endpoint, if any.
+    upper: Option<Bound>,
+}
+
+impl Interval {
+    /// Returns the interval of the values `v` such that `v <comparison>
+    /// value`, if it is an interval.
+    pub(crate) fn from_comparison(comparison: Comparison, value: f64) -> Option<Self> {
+        let bound = |inclusive| Some(Bound { value, inclusive });
+        match comparison {
+            Comparison::Lt => Some(Self {
+                lower: None,
+                upper: bound(false),
+            }),
+            Comparison::LtEq => Some(Self {
+                lower: None,
+                upper: bound(true),
+            }),
+            Comparison::Eq => Some(Self {
+                lower: bound(true),
+                upper: bound(true),
+            }),
+            Comparison::GtEq => Some(Self {
+                lower: bound(true),
+                upper: None,
+            }),
+            Comparison::Gt => Some(Self {
+                lower: bound(false),
+                upper: None,
+            }),
+            Comparison::NotEq => None,
+        }
+    }
+
+    /// Returns the values belonging to both intervals.
+    pub(crate) fn intersection(self, other: Self) -> Self {
+        // Of two lower endpoints, the tighter is the greater one, and of two
+        // upper endpoints the lesser one; with the same value, the exclusive
+        // endpoint is the tighter.
+        let tighter = |left: Option<Bound>, right: Option<Bound>, tighter_ordering: Ordering| match (
+            left, right,
+        ) {
+            (Some(left), Some(right)) => Some(match left.value.total_cmp(&right.value) {
+                Ordering::Equal if left.inclusive => right,
+                Ordering::Equal => left,
+                ordering if ordering == tighter_ordering => left,
+                _ => right,
+            }),
+            (bound, None) | (None, bound) => bound,
+        };
+        Self {
+            lower: tighter(self.lower, other.lower, Ordering::Greater),
+            upper: tighter(self.upper, other.upper, Ordering::Less),
+        }
+    }
+
+    /// Returns whether no value belongs to the interval.
+    pub(crate) fn is_empty(&self) -> bool {
+        match (self.lower, self.upper) {
+            (Some(lower), Some(upper)) => match lower.value.total_cmp(&upper.value) {
+                Ordering::Less => false,
+                Ordering::Equal => !(lower.inclusive && upper.inclusive),
+                Ordering::Greater => true,
+            },
+            _ => false,
+        }
+    }
+
+    /// Returns whether the provided value belongs to the interval.
+    pub(crate) fn contains_value(&self, value: f64) -> bool {
+        let point = Self {
+            lower: Some(Bound {
+                value,
+                inclusive: true,
+            }),
+            upper: Some(Bound {
+                value,
+                inclusive: true,
+            }),
+        };
+        self.contains(&point)
+    }
+
+    /// Returns whether all the values of the other interval belong to this
+    /// interval.
+    pub(crate) fn contains(&self, other: &Self) -> bool {
+        let lower_ok = match (self.lower, other.lower) {
+            (None, _) => true,
+            (Some(_), None) => false,
+            (Some(outer), Some(inner)) => match outer.value.total_cmp(&inner.value) {
+                Ordering::Less => true,
+                Ordering::Equal => outer.inclusive || !inner.inclusive,
+                Ordering::Greater => false,
+            },
+        };
+        let upper_ok = match (self.upper, other.upper) {
+            (None, _) => true,
+            (Some(_), None) => false,
+            (Some(outer), Some(inner)) => match outer.value.total_cmp(&inner.value) {
+                Ordering::Greater => true,
+                Ordering::Equal => outer.inclusive || !inner.inclusive,
+                Ordering::Less => false,
+            },
+        };
+        lower_ok && upper_ok
+    }
+}
+
+/// The bounds which a check constraint places on its subjects, i.e. on the
+/// columns or expressions (such as `length(name)`) compared to numeric
+/// literals or to `IN` lists in its top-level conjunction.
+pub(crate) struct ConstraintBounds {
+    /// The interval of each subject, keyed by its canonical form.
+    pub(crate) intervals: BTreeMap<String, Interval>,
+    /// The allowed values of each subject constrained by an `IN` list, keyed
+    /// by its canonical form, as rendered by [`list_value`].
+    pub(crate) value_sets: BTreeMap<String, BTreeSet<String>>,
+    /// Whether the intervals are exactly equivalent to the constraint, i.e.
+    /// all of its conjuncts are comparisons with numeric literals.
+    pub(crate) is_exact: bool,
+}
+
+/// Returns the value of the provided item of an `IN` list in a form which
+/// can be compared across lists: numeric literals are rendered from their
+/// value, so that `1`, `1.0` and `+1` coincide and `-1` parses back as a
+/// number, while any other item falls back to its canonical form.
+fn list_value(expr: &Expr) -> String {
+    match numeric_literal(expr) {
+        // Adding zero turns a negative zero into a positive one.
+        Some(value) => format!("{}", value + 0.0),
+        None => canonical_form(expr),
+    }
+}
+
+/// Returns whether the provided expression is the empty string literal.
+fn is_empty_string(expr: &Expr) -> bool {
+    matches!(
+        strip_parentheses_and_casts(expr),
+        Expr::Value(ValueWithSpan {
+            value: Value::SingleQuotedString(string),
+            ..
+        }) if string.is_empty()
+    )
+}
+
+/// Returns the subject and the interval of the provided comparison with a
+/// numeric literal, or of the provided `BETWEEN` with numeric literals. A
+/// not-empty check such as `name <> ''` bounds the length of its subject.
+fn comparison_interval(expr: &Expr) -> Option<(String, Interval)> {
+    match strip_parentheses(expr) {
+        Expr::BinaryOp {
+            left,
+            op: BinaryOperator::NotEq,
+            right,
+        } if is_empty_string(left) || is_empty_string(right) => {
+            let subject = if is_empty_string(right) { left } else { right };
+            Some((
+                format!("length({})", canonical_form(subject)),
+                Interval::from_comparison(Comparison::Gt, 0.0)?,
+            ))
+        }
+        Expr::BinaryOp { left, op, right } => {
+            let comparison = Comparison::from_operator(op)?;
+            let (subject, comparison, value) = match (numeric_literal(left), numeric_literal(right))
+            {
+                (None, Some(value)) => (left, comparison, value),
+                (Some(value), None) => (right, comparison.flipped(), value),
+                _ => return None,
+            };
+            Some((
+                canonical_form(subject),
+                Interval::from_comparison(comparison, value)?,
+            ))
+        }
+        Expr::Between {
+            expr,
+            negated: false,
+            low,
+            high,
+        } => {
+            let lower = Interval::from_comparison(Comparison::GtEq, numeric_literal(low)?)?;
+            let upper = Interval::from_comparison(Comparison::LtEq, numeric_literal(high)?)?;
+            Some((canonical_form(expr), lower.intersection(upper)))
+        }
+        _ => None,
+    }
+}
+
+/// Returns the bounds which the provided check expression places on its
+/// subjects.
+pub(crate) fn constraint_bounds(expr: &Expr) -> ConstraintBounds {
+    let mut bounds = ConstraintBounds {
+        intervals: BTreeMap::new(),
+        value_sets: BTreeMap::new(),
+        is_exact: true,
+    };
+    for conjunct in conjuncts(expr) {
+        if let Expr::InList {
+            expr,
+            list,
+            negated: false,
+        } = conjunct
+        {
+            let values = list.iter().map(list_value).collect::<BTreeSet<_>>();
+            bounds
+                .value_sets
+                .entry(canonical_form(expr))
+                .and_modify(|existing| existing.retain(|value| values.contains(value)))
+                .or_insert(values);
+            bounds.is_exact = false;
+            continue;
+        }
+        let Some((subject, interval)) = comparison_interval(conjunct) else {
+            bounds.is_exact = false;
+            continue;
+        };
+        bounds
+            .intervals
+            .entry(subject)
+            .and_modify(|existing| *existing = existing.intersection(interval))
+            .or_insert(interval);
+    }
+    bounds
+}
@@ -8,7 +8,7 @@ use std::borrow::Borrow;
 use sql_traits::traits::{ColumnLike, DatabaseLike, ForeignKeyLike, TableLike};
 
 use crate::{
-    error::RuleErrorInfo,
+    error::{ObjectRef, RuleErrorInfo},
     traits::{Constrainer, ForeignKeyRule, GenericConstrainer},
 };
 
@@ -209,12 +209,7 @@ impl<DB: DatabaseLike> ForeignKeyRule for CompatibleForeignKey<DB> {
                 let error: RuleErrorInfo = RuleErrorInfo::builder()
                     .rule("CompatibleForeignKey")
                     .unwrap()
-                    .object(
-                        foreign_key
-                            .foreign_key_name()
-                            .unwrap_or("Unnamed foreign key")
-                            .to_owned(),
-                    )
+                    .object(ObjectRef::foreign_key(database, foreign_key))
                     .unwrap()
                     .message(message)
                     .unwrap()
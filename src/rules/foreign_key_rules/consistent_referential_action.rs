@@ -0,0 +1,141 @@
+//! Submodule providing the `ConsistentReferentialAction` constraint, which
+//! enforces that the referential actions of foreign keys can be carried out
+//! on their host columns.
+
+use sql_traits::traits::{ColumnLike, DatabaseLike, ForeignKeyLike, TableLike};
+use sqlparser::ast::ReferentialAction;
+
+use crate::{
+    error::{ObjectRef, RuleErrorInfo},
+    traits::{Constrainer, ForeignKeyRule, GenericConstrainer},
+};
+
+/// Struct defining a constraint that enforces that the `ON DELETE` and
+/// `ON UPDATE` actions of foreign keys can be carried out on their host
+/// columns:
+///
+/// - `SET NULL` requires all host columns to be nullable.
+/// - `SET DEFAULT` requires all host columns to have a default value.
+///
+/// # Example
+///
+/// Here follows an example of validating an invalid SQL statement with the
+/// `ConsistentReferentialAction` constraint.
+///
+/// ```rust
+/// use sql_rules::prelude::*;
+/// use sqlparser::dialect::GenericDialect;
+///
+/// let constrainer: GenericConstrainer<ParserDB> = ConsistentReferentialAction::default().into();
+///
+/// let invalid_set_null = ParserDB::parse::<GenericDialect>(
+///     "
+/// CREATE TABLE users (id INT PRIMARY KEY);
+/// CREATE TABLE posts (id INT PRIMARY KEY, user_id INT NOT NULL REFERENCES users (id) ON DELETE SET NULL);
+/// ",
+/// )
+/// .unwrap();
+/// assert!(constrainer.validate_schema(&invalid_set_null).is_err());
+///
+/// let invalid_set_default = ParserDB::parse::<GenericDialect>(
+///     "
+/// CREATE TABLE users (id INT PRIMARY KEY);
+/// CREATE TABLE posts (id INT PRIMARY KEY, user_id INT REFERENCES users (id) ON DELETE SET DEFAULT);
+/// ",
+/// )
+/// .unwrap();
+/// assert!(constrainer.validate_schema(&invalid_set_default).is_err());
+///
+/// let valid_schema = ParserDB::parse::<GenericDialect>(
+///     "
+/// CREATE TABLE users (id INT PRIMARY KEY);
+/// CREATE TABLE posts (
+///     id INT PRIMARY KEY,
+///     user_id INT REFERENCES users (id) ON DELETE SET NULL,
+///     editor_id INT NOT NULL DEFAULT 1 REFERENCES users (id) ON DELETE SET DEFAULT
+/// );
+/// ",
+/// )
+/// .unwrap();
+/// assert!(constrainer.validate_schema(&valid_schema).is_ok());
+/// ```
+pub struct ConsistentReferentialAction<DB>(std::marker::PhantomData<DB>);
+
+impl<DB> Default for ConsistentReferentialAction<DB> {
+    fn default() -> Self {
+        Self(std::marker::PhantomData)
+    }
+}
+
+impl<DB: DatabaseLike + 'static> From<ConsistentReferentialAction<DB>> for GenericConstrainer<DB> {
+    fn from(constraint: ConsistentReferentialAction<DB>) -> Self {
+        let mut constrainer = GenericConstrainer::default();
+        constrainer.register_foreign_key_rule(Box::new(constraint));
+        constrainer
+    }
+}
+
+impl<DB: DatabaseLike> ForeignKeyRule for ConsistentReferentialAction<DB> {
+    type Database = DB;
+
+    fn validate_foreign_key(
+        &self,
+        database: &Self::Database,
+        foreign_key: &<Self::Database as DatabaseLike>::ForeignKey,
+    ) -> Result<(), crate::error::Error<DB>> {
+        let host_table_name = foreign_key.host_table(database).table_name();
+        let actions = [
+            ("ON DELETE", foreign_key.on_delete(database)),
+            ("ON UPDATE", foreign_key.on_update(database)),
+        ];
+
+        for (event, action) in actions {
+            let offending_column = match action {
+                Some(ReferentialAction::SetNull) => foreign_key
+                    .host_columns(database)
+                    .find(|column| !column.is_nullable(database)),
+                Some(ReferentialAction::SetDefault) => foreign_key
+                    .host_columns(database)
+                    .find(|column| !column.has_default()),
+                _ => None,
+            };
+            let (Some(action), Some(column)) = (action, offending_column) else {
+                continue;
+            };
+            let column_name = column.column_name();
+            let (problem, resolution) = if action == ReferentialAction::SetNull {
+                (
+                    "is NOT NULL",
+                    format!(
+                        "Make column '{host_table_name}.{column_name}' nullable, or use a different {event} action."
+                    ),
+                )
+            } else {
+                (
+                    "has no default value",
+                    format!(
+                        "Add a default value to column '{host_table_name}.{column_name}', or use a different {event} action."
+                    ),
+                )
+            };
+            let error: RuleErrorInfo = RuleErrorInfo::builder()
+                .rule("ConsistentReferentialAction")
+                .unwrap()
+                .object(ObjectRef::foreign_key(database, foreign_key))
+                .unwrap()
+                .message(format!(
+                    "Foreign key on '{host_table_name}.{column_name}' declares {event} {action}, but the column {problem}."
+                ))
+                .unwrap()
+                .resolution(resolution)
+                .unwrap()
+                .try_into()
+                .unwrap();
+            return Err(crate::error::Error::ForeignKey(
+                Box::new(foreign_key.clone()),
+                error.into(),
+            ));
+        }
+        Ok(())
+    }
+}
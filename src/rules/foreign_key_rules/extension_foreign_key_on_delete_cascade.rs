@@ -5,7 +5,7 @@
 use sql_traits::traits::{ColumnLike, DatabaseLike, ForeignKeyLike, TableLike};
 
 use crate::{
-    error::RuleErrorInfo,
+    error::{ObjectRef, RuleErrorInfo},
     traits::{Constrainer, ForeignKeyRule, GenericConstrainer},
 };
 
@@ -126,7 +126,7 @@ impl<DB: DatabaseLike> ForeignKeyRule for ExtensionForeignKeyOnDeleteCascade<DB>
             let error: RuleErrorInfo = RuleErrorInfo::builder()
                 .rule("ExtensionForeignKeyOnDeleteCascade")
                 .unwrap()
-                .object(fk_name.clone())
+                .object(ObjectRef::foreign_key(database, foreign_key))
                 .unwrap()
                 .message(format!(
                     "Extension foreign key '{}' in table '{}' must have ON DELETE CASCADE",
@@ -0,0 +1,161 @@
+//! Submodule providing the `ForeignKeyHostColumnsIndexed` constraint, which
+//! enforces that the host columns of a foreign key are covered by an index.
+
+use sql_traits::traits::{ColumnLike, DatabaseLike, ForeignKeyLike, IndexLike, TableLike};
+
+use crate::{
+    error::{ObjectRef, RuleErrorInfo},
+    traits::{Constrainer, ForeignKeyRule, GenericConstrainer},
+};
+
+/// Struct defining a constraint that enforces that the host columns of a
+/// foreign key are the leading columns of an index, of a unique index or of
+/// the primary key of the host table.
+///
+/// Without such an index, deleting or updating a referenced row requires a
+/// full scan of the host table, which is particularly costly for cascading
+/// deletes.
+///
+/// # Example
+///
+/// Here follows an example of validating an invalid SQL statement with the
+/// `ForeignKeyHostColumnsIndexed` constraint.
+///
+/// ```rust
+/// use sql_rules::prelude::*;
+/// use sqlparser::dialect::GenericDialect;
+///
+/// let constrainer: GenericConstrainer<ParserDB> = ForeignKeyHostColumnsIndexed::default().into();
+///
+/// // The host column is not indexed
+/// let invalid_schema = ParserDB::parse::<GenericDialect>(
+///     "
+/// CREATE TABLE parents (id INT PRIMARY KEY);
+/// CREATE TABLE children (
+///     id INT PRIMARY KEY,
+///     parent_id INT REFERENCES parents(id)
+/// );
+/// ",
+/// )
+/// .unwrap();
+/// assert!(constrainer.validate_schema(&invalid_schema).is_err());
+///
+/// // The host column is the leading column of an index
+/// let valid_schema = ParserDB::parse::<GenericDialect>(
+///     "
+/// CREATE TABLE parents (id INT PRIMARY KEY);
+/// CREATE TABLE children (
+///     id INT PRIMARY KEY,
+///     parent_id INT REFERENCES parents(id),
+///     position INT
+/// );
+/// CREATE INDEX children_parent_id_position_idx ON children (parent_id, position);
+/// ",
+/// )
+/// .unwrap();
+/// assert!(constrainer.validate_schema(&valid_schema).is_ok());
+///
+/// // The host column is the primary key, as in extension tables
+/// let valid_schema = ParserDB::parse::<GenericDialect>(
+///     "
+/// CREATE TABLE parents (id INT PRIMARY KEY);
+/// CREATE TABLE children (id INT PRIMARY KEY REFERENCES parents(id) ON DELETE CASCADE);
+/// ",
+/// )
+/// .unwrap();
+/// assert!(constrainer.validate_schema(&valid_schema).is_ok());
+/// ```
+pub struct ForeignKeyHostColumnsIndexed<DB>(std::marker::PhantomData<DB>);
+
+impl<DB> Default for ForeignKeyHostColumnsIndexed<DB> {
+    fn default() -> Self {
+        Self(std::marker::PhantomData)
+    }
+}
+
+impl<DB: DatabaseLike + 'static> From<ForeignKeyHostColumnsIndexed<DB>> for GenericConstrainer<DB> {
+    fn from(constraint: ForeignKeyHostColumnsIndexed<DB>) -> Self {
+        let mut constrainer = GenericConstrainer::default();
+        constrainer.register_foreign_key_rule(Box::new(constraint));
+        constrainer
+    }
+}
+
+/// Returns whether the provided host columns are, in any order, the leading
+/// columns of the provided index columns.
+fn is_leading_prefix<'a>(
+    index_columns: impl Iterator<Item = &'a str>,
+    host_columns: &[&str],
+) -> bool {
+    let prefix = index_columns.take(host_columns.len()).collect::<Vec<_>>();
+    prefix.len() == host_columns.len()
+        && host_columns
+            .iter()
+            .all(|host_column| prefix.contains(host_column))
+}
+
+impl<DB: DatabaseLike> ForeignKeyRule for ForeignKeyHostColumnsIndexed<DB> {
+    type Database = DB;
+
+    fn validate_foreign_key(
+        &self,
+        database: &Self::Database,
+        foreign_key: &<Self::Database as DatabaseLike>::ForeignKey,
+    ) -> Result<(), crate::error::Error<DB>> {
+        let host_table = foreign_key.host_table(database);
+        let host_columns = foreign_key
+            .host_columns(database)
+            .map(ColumnLike::column_name)
+            .collect::<Vec<_>>();
+
+        let covered_by_primary_key = is_leading_prefix(
+            host_table
+                .primary_key_columns(database)
+                .map(ColumnLike::column_name),
+            &host_columns,
+        );
+        let covered_by_index = host_table.indices(database).any(|index| {
+            is_leading_prefix(
+                index.columns(database).map(ColumnLike::column_name),
+                &host_columns,
+            )
+        });
+        let covered_by_unique_index = host_table.unique_indices(database).any(|index| {
+            is_leading_prefix(
+                index.columns(database).map(ColumnLike::column_name),
+                &host_columns,
+            )
+        });
+
+        if covered_by_primary_key || covered_by_index || covered_by_unique_index {
+            return Ok(());
+        }
+
+        let object = ObjectRef::foreign_key(database, foreign_key);
+        let table_name = host_table.table_name();
+        let columns = host_columns.join(", ");
+        let index_name = format!("{table_name}_{}_idx", host_columns.join("_"));
+        let message = format!(
+            "Foreign key '{object}' has host columns ({columns}) which are not the leading columns of any index or of the primary key of table '{table_name}'."
+        );
+        let resolution = format!(
+            "Add an index on the host columns: `CREATE INDEX {index_name} ON {} ({columns});`",
+            object.qualified_table_name()
+        );
+        let error: RuleErrorInfo = RuleErrorInfo::builder()
+            .rule("ForeignKeyHostColumnsIndexed")
+            .unwrap()
+            .object(object)
+            .unwrap()
+            .message(message)
+            .unwrap()
+            .resolution(resolution)
+            .unwrap()
+            .try_into()
+            .unwrap();
+        Err(crate::error::Error::ForeignKey(
+            Box::new(foreign_key.clone()),
+            error.into(),
+        ))
+    }
+}
@@ -5,7 +5,7 @@
 use sql_traits::traits::{ColumnLike, DatabaseLike, ForeignKeyLike, TableLike};
 
 use crate::{
-    error::RuleErrorInfo,
+    error::{ObjectRef, RuleErrorInfo},
     traits::{Constrainer, ForeignKeyRule, GenericConstrainer},
 };
 
@@ -116,12 +116,7 @@ impl<DB: DatabaseLike> ForeignKeyRule for PrimaryKeyReferenceEndsWithId<DB> {
                 let error: RuleErrorInfo = RuleErrorInfo::builder()
                     .rule("PrimaryKeyReferenceEndsWithId")
                     .unwrap()
-                    .object(
-                        foreign_key
-                            .foreign_key_name()
-                            .unwrap_or("Unnamed foreign key")
-                            .to_owned(),
-                    )
+                    .object(ObjectRef::foreign_key(database, foreign_key))
                     .unwrap()
                     .message(format!(
                         "Foreign key host column '{}.{}' references a primary key column '{}.{}' but does not end with 'id'",
@@ -0,0 +1,263 @@
+//! Submodule providing the `PrimaryKeyReferenceNamedAfterTable` constraint,
+//! which enforces that single-column foreign keys referencing a primary key
+//! are named after the singularized referenced table.
+
+use sql_traits::traits::{ColumnLike, DatabaseLike, ForeignKeyLike, TableLike};
+
+use crate::{
+    error::{ObjectRef, RuleErrorInfo},
+    rules::InflectionDictionary,
+    traits::{Constrainer, ForeignKeyRule, GenericConstrainer},
+};
+
+/// Struct defining a constraint that enforces that the host column of a
+/// single-column foreign key referencing the primary key of another table is
+/// named `<singular referenced table>_id`.
+///
+/// When the host table references the same table more than once, or
+/// references itself, the host columns must instead be named
+/// `<role>_<singular referenced table>_id`, as in `author_user_id` and
+/// `reviewer_user_id`. The last segment of the referenced table name is
+/// singularized with an [`InflectionDictionary`], so that `taxonomic_ranks`
+/// becomes `taxonomic_rank` and `taxa` becomes `taxon`.
+///
+/// Extension foreign keys, whose host columns are the primary key of the
+/// host table, are not subject to this rule.
+///
+/// # Example
+///
+/// Here follows an example of validating an invalid SQL statement with the
+/// `PrimaryKeyReferenceNamedAfterTable` constraint.
+///
+/// ```rust
+/// use sql_rules::prelude::*;
+/// use sqlparser::dialect::GenericDialect;
+///
+/// let constrainer: GenericConstrainer<ParserDB> =
+///     PrimaryKeyReferenceNamedAfterTable::default().into();
+///
+/// let invalid_schema = ParserDB::parse::<GenericDialect>(
+///     "
+/// CREATE TABLE users (id INT PRIMARY KEY);
+/// CREATE TABLE posts (id INT PRIMARY KEY, author_id INT REFERENCES users (id));
+/// ",
+/// )
+/// .unwrap();
+/// assert!(constrainer.validate_schema(&invalid_schema).is_err());
+///
+/// let valid_schema = ParserDB::parse::<GenericDialect>(
+///     "
+/// CREATE TABLE users (id INT PRIMARY KEY);
+/// CREATE TABLE posts (id INT PRIMARY KEY, user_id INT REFERENCES users (id));
+/// ",
+/// )
+/// .unwrap();
+/// assert!(constrainer.validate_schema(&valid_schema).is_ok());
+///
+/// // Scientific plurals are singularized through the dictionary
+/// let valid_schema = ParserDB::parse::<GenericDialect>(
+///     "
+/// CREATE TABLE taxa (id INT PRIMARY KEY);
+/// CREATE TABLE spectra (id INT PRIMARY KEY);
+/// CREATE TABLE observations (
+///     id INT PRIMARY KEY,
+///     taxon_id INT REFERENCES taxa (id),
+///     spectrum_id INT REFERENCES spectra (id)
+/// );
+/// ",
+/// )
+/// .unwrap();
+/// assert!(constrainer.validate_schema(&valid_schema).is_ok());
+///
+/// // A table referencing the same table twice must qualify the columns with a role
+/// let invalid_schema = ParserDB::parse::<GenericDialect>(
+///     "
+/// CREATE TABLE users (id INT PRIMARY KEY);
+/// CREATE TABLE posts (
+///     id INT PRIMARY KEY,
+///     user_id INT REFERENCES users (id),
+///     reviewer_user_id INT REFERENCES users (id)
+/// );
+/// ",
+/// )
+/// .unwrap();
+/// assert!(constrainer.validate_schema(&invalid_schema).is_err());
+///
+/// let valid_schema = ParserDB::parse::<GenericDialect>(
+///     "
+/// CREATE TABLE users (id INT PRIMARY KEY);
+/// CREATE TABLE posts (
+///     id INT PRIMARY KEY,
+///     author_user_id INT REFERENCES users (id),
+///     reviewer_user_id INT REFERENCES users (id)
+/// );
+/// ",
+/// )
+/// .unwrap();
+/// assert!(constrainer.validate_schema(&valid_schema).is_ok());
+///
+/// // Roles may also be allowed for single references
+/// let constrainer: GenericConstrainer<ParserDB> =
+///     PrimaryKeyReferenceNamedAfterTable::default().with_single_reference_roles(true).into();
+/// let valid_schema = ParserDB::parse::<GenericDialect>(
+///     "
+/// CREATE TABLE users (id INT PRIMARY KEY);
+/// CREATE TABLE posts (id INT PRIMARY KEY, author_user_id INT REFERENCES users (id));
+/// ",
+/// )
+/// .unwrap();
+/// assert!(constrainer.validate_schema(&valid_schema).is_ok());
+/// ```
+pub struct PrimaryKeyReferenceNamedAfterTable<DB> {
+    suffix: String,
+    single_reference_roles: bool,
+    dictionary: InflectionDictionary,
+    _phantom: std::marker::PhantomData<DB>,
+}
+
+impl<DB> PrimaryKeyReferenceNamedAfterTable<DB> {
+    /// Sets the suffix appended to the singularized referenced table name,
+    /// by default `_id`.
+    #[must_use]
+    pub fn with_suffix(mut self, suffix: impl Into<String>) -> Self {
+        self.suffix = suffix.into();
+        self
+    }
+
+    /// Sets whether host columns of tables referencing the target table only
+    /// once may also be qualified with a role, by default `false`.
+    #[must_use]
+    pub fn with_single_reference_roles(mut self, single_reference_roles: bool) -> Self {
+        self.single_reference_roles = single_reference_roles;
+        self
+    }
+
+    /// Sets the dictionary of the exceptions to the inflection rules.
+    #[must_use]
+    pub fn with_dictionary(mut self, dictionary: InflectionDictionary) -> Self {
+        self.dictionary = dictionary;
+        self
+    }
+}
+
+impl<DB> Default for PrimaryKeyReferenceNamedAfterTable<DB> {
+    fn default() -> Self {
+        Self {
+            suffix: "_id".to_string(),
+            single_reference_roles: false,
+            dictionary: InflectionDictionary::default(),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<DB: DatabaseLike + 'static> From<PrimaryKeyReferenceNamedAfterTable<DB>>
+    for GenericConstrainer<DB>
+{
+    fn from(constraint: PrimaryKeyReferenceNamedAfterTable<DB>) -> Self {
+        let mut constrainer = GenericConstrainer::default();
+        constrainer.register_foreign_key_rule(Box::new(constraint));
+        constrainer
+    }
+}
+
+impl<DB: DatabaseLike> PrimaryKeyReferenceNamedAfterTable<DB> {
+    /// Returns the referenced column if the provided foreign key is a
+    /// single-column, non-extension reference to a primary key.
+    fn primary_key_reference<'db>(
+        database: &'db DB,
+        foreign_key: &'db DB::ForeignKey,
+    ) -> Option<&'db DB::Column> {
+        if foreign_key.is_extension_foreign_key(database) {
+            return None;
+        }
+        let mut referenced_columns = foreign_key.referenced_columns(database);
+        let referenced_column = referenced_columns.next()?;
+        if referenced_columns.next().is_some() {
+            return None;
+        }
+        foreign_key
+            .referenced_table(database)
+            .is_primary_key_column(database, referenced_column)
+            .then_some(referenced_column)
+    }
+}
+
+impl<DB: DatabaseLike> ForeignKeyRule for PrimaryKeyReferenceNamedAfterTable<DB> {
+    type Database = DB;
+
+    fn validate_foreign_key(
+        &self,
+        database: &Self::Database,
+        foreign_key: &<Self::Database as DatabaseLike>::ForeignKey,
+    ) -> Result<(), crate::error::Error<DB>> {
+        if Self::primary_key_reference(database, foreign_key).is_none() {
+            return Ok(());
+        }
+        let Some(host_column) = foreign_key.host_columns(database).next() else {
+            return Ok(());
+        };
+
+        let host_table = foreign_key.host_table(database);
+        let referenced_table = foreign_key.referenced_table(database);
+        let references_to_target = host_table
+            .foreign_keys(database)
+            .filter(|fk| {
+                fk.referenced_table(database) == referenced_table
+                    && Self::primary_key_reference(database, fk).is_some()
+            })
+            .count();
+        let role_required = references_to_target > 1 || host_table == referenced_table;
+        let role_allowed = role_required || self.single_reference_roles;
+
+        let column_name = host_column.column_name();
+        let expected_name = format!(
+            "{}{}",
+            self.dictionary
+                .singularize_name(referenced_table.table_name()),
+            self.suffix
+        );
+        let has_expected_name = column_name == expected_name;
+        let has_role_name = column_name.len() > expected_name.len() + 1
+            && column_name.ends_with(&format!("_{expected_name}"));
+
+        if (has_expected_name && !role_required) || (has_role_name && role_allowed) {
+            return Ok(());
+        }
+
+        let host_table_name = host_table.table_name();
+        let referenced_table_name = referenced_table.table_name();
+        let (message, resolution) = if role_required {
+            (
+                format!(
+                    "Foreign key host column '{host_table_name}.{column_name}' references table '{referenced_table_name}', which table '{host_table_name}' references more than once or is the table itself, but is not named `<role>_{expected_name}`"
+                ),
+                format!(
+                    "Rename column '{host_table_name}.{column_name}' to describe the role of the reference, e.g. 'parent_{expected_name}'"
+                ),
+            )
+        } else {
+            (
+                format!(
+                    "Foreign key host column '{host_table_name}.{column_name}' references the primary key of table '{referenced_table_name}' but is not named '{expected_name}'"
+                ),
+                format!("Rename column '{host_table_name}.{column_name}' to '{expected_name}'"),
+            )
+        };
+        let error: RuleErrorInfo = RuleErrorInfo::builder()
+            .rule("PrimaryKeyReferenceNamedAfterTable")
+            .unwrap()
+            .object(ObjectRef::foreign_key(database, foreign_key))
+            .unwrap()
+            .message(message)
+            .unwrap()
+            .resolution(resolution)
+            .unwrap()
+            .try_into()
+            .unwrap();
+        Err(crate::error::Error::ForeignKey(
+            Box::new(foreign_key.clone()),
+            error.into(),
+        ))
+    }
+}
@@ -3,7 +3,7 @@
 //! in the referenced table.
 
 use crate::{
-    error::RuleErrorInfo,
+    error::{ObjectRef, RuleErrorInfo},
     traits::{Constrainer, ForeignKeyRule, GenericConstrainer},
 };
 use sql_traits::traits::IndexLike;
@@ -106,12 +106,7 @@ impl<DB: DatabaseLike> ForeignKeyRule for ReferencesUniqueIndex<DB> {
             let error: RuleErrorInfo = RuleErrorInfo::builder()
                 .rule("ReferencesUniqueIndex")
                 .unwrap()
-                .object(
-                    foreign_key
-                        .foreign_key_name()
-                        .unwrap_or("Unnamed foreign key")
-                        .to_owned(),
-                )
+                .object(ObjectRef::foreign_key(database, foreign_key))
                 .unwrap()
                 .message(format!(
                     "Foreign key from table '{}' references columns ({}) in table '{}' which are not covered by a unique index",
@@ -0,0 +1,181 @@
+//! Submodule providing the `ReferentialActionPolicy` constraint, which
+//! enforces a configurable policy on the referential actions of non-extension
+//! foreign keys.
+
+use sql_traits::traits::{DatabaseLike, ForeignKeyLike, TableLike};
+use sqlparser::ast::ReferentialAction;
+
+use crate::{
+    error::{ObjectRef, RuleErrorInfo},
+    traits::{Constrainer, ForeignKeyRule, GenericConstrainer},
+};
+
+/// Struct defining a constraint that enforces a configurable policy on the
+/// referential actions of non-extension foreign keys.
+///
+/// - By default, the `ON DELETE` action must be explicitly declared, so that
+///   the behaviour when deleting referenced rows is a deliberate choice. The
+///   same may be required of the `ON UPDATE` action.
+/// - Foreign keys referencing a table marked as reference data (e.g. a table
+///   of units or of countries) must not declare `ON DELETE CASCADE`, as
+///   deleting a reference row would silently wipe the rows using it.
+///
+/// Extension foreign keys are covered by `ExtensionForeignKeyOnDeleteCascade`
+/// and are not subject to this rule.
+///
+/// # Example
+///
+/// Here follows an example of validating an invalid SQL statement with the
+/// `ReferentialActionPolicy` constraint.
+///
+/// ```rust
+/// use sql_rules::prelude::*;
+/// use sqlparser::dialect::GenericDialect;
+///
+/// let constrainer: GenericConstrainer<ParserDB> = ReferentialActionPolicy::default()
+///     .with_reference_data_table("units")
+///     .into();
+///
+/// // Implicit ON DELETE action
+/// let invalid_schema = ParserDB::parse::<GenericDialect>(
+///     "
+/// CREATE TABLE users (id INT PRIMARY KEY);
+/// CREATE TABLE posts (id INT PRIMARY KEY, user_id INT REFERENCES users (id));
+/// ",
+/// )
+/// .unwrap();
+/// assert!(constrainer.validate_schema(&invalid_schema).is_err());
+///
+/// // Cascading deletes from reference data
+/// let invalid_schema = ParserDB::parse::<GenericDialect>(
+///     "
+/// CREATE TABLE units (id INT PRIMARY KEY);
+/// CREATE TABLE measurements (id INT PRIMARY KEY, unit_id INT REFERENCES units (id) ON DELETE CASCADE);
+/// ",
+/// )
+/// .unwrap();
+/// assert!(constrainer.validate_schema(&invalid_schema).is_err());
+///
+/// let valid_schema = ParserDB::parse::<GenericDialect>(
+///     "
+/// CREATE TABLE units (id INT PRIMARY KEY);
+/// CREATE TABLE measurements (id INT PRIMARY KEY, unit_id INT REFERENCES units (id) ON DELETE RESTRICT);
+/// ",
+/// )
+/// .unwrap();
+/// assert!(constrainer.validate_schema(&valid_schema).is_ok());
+/// ```
+pub struct ReferentialActionPolicy<DB> {
+    require_explicit_on_delete: bool,
+    require_explicit_on_update: bool,
+    reference_data_tables: Vec<String>,
+    _phantom: std::marker::PhantomData<DB>,
+}
+
+impl<DB> ReferentialActionPolicy<DB> {
+    /// Sets whether non-extension foreign keys must explicitly declare their
+    /// `ON DELETE` action, by default `true`.
+    #[must_use]
+    pub fn with_explicit_on_delete(mut self, required: bool) -> Self {
+        self.require_explicit_on_delete = required;
+        self
+    }
+
+    /// Sets whether non-extension foreign keys must explicitly declare their
+    /// `ON UPDATE` action, by default `false`.
+    #[must_use]
+    pub fn with_explicit_on_update(mut self, required: bool) -> Self {
+        self.require_explicit_on_update = required;
+        self
+    }
+
+    /// Marks the table with the provided name as reference data, which
+    /// foreign keys must not reference with `ON DELETE CASCADE`.
+    #[must_use]
+    pub fn with_reference_data_table(mut self, table_name: impl Into<String>) -> Self {
+        self.reference_data_tables.push(table_name.into());
+        self
+    }
+}
+
+impl<DB> Default for ReferentialActionPolicy<DB> {
+    fn default() -> Self {
+        Self {
+            require_explicit_on_delete: true,
+            require_explicit_on_update: false,
+            reference_data_tables: Vec::new(),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<DB: DatabaseLike + 'static> From<ReferentialActionPolicy<DB>> for GenericConstrainer<DB> {
+    fn from(constraint: ReferentialActionPolicy<DB>) -> Self {
+        let mut constrainer = GenericConstrainer::default();
+        constrainer.register_foreign_key_rule(Box::new(constraint));
+        constrainer
+    }
+}
+
+impl<DB: DatabaseLike> ForeignKeyRule for ReferentialActionPolicy<DB> {
+    type Database = DB;
+
+    fn validate_foreign_key(
+        &self,
+        database: &Self::Database,
+        foreign_key: &<Self::Database as DatabaseLike>::ForeignKey,
+    ) -> Result<(), crate::error::Error<DB>> {
+        if foreign_key.is_extension_foreign_key(database) {
+            return Ok(());
+        }
+
+        let object = ObjectRef::foreign_key(database, foreign_key);
+        let referenced_table_name = foreign_key.referenced_table(database).table_name();
+        let on_delete = foreign_key.on_delete(database);
+
+        let violation = if self.require_explicit_on_delete && on_delete.is_none() {
+            Some((
+                format!("Foreign key '{object}' does not declare an ON DELETE action."),
+                "Declare the intended ON DELETE action explicitly (e.g. `ON DELETE RESTRICT` or `ON DELETE CASCADE`).".to_string(),
+            ))
+        } else if self.require_explicit_on_update && foreign_key.on_update(database).is_none() {
+            Some((
+                format!("Foreign key '{object}' does not declare an ON UPDATE action."),
+                "Declare the intended ON UPDATE action explicitly (e.g. `ON UPDATE RESTRICT` or `ON UPDATE CASCADE`).".to_string(),
+            ))
+        } else if on_delete == Some(ReferentialAction::Cascade)
+            && self
+                .reference_data_tables
+                .iter()
+                .any(|table_name| table_name.eq_ignore_ascii_case(referenced_table_name))
+        {
+            Some((
+                format!(
+                    "Foreign key '{object}' cascades deletes from '{referenced_table_name}', which is reference data."
+                ),
+                "Use `ON DELETE RESTRICT` or `ON DELETE NO ACTION`, so that reference rows in use cannot be deleted.".to_string(),
+            ))
+        } else {
+            None
+        };
+
+        if let Some((message, resolution)) = violation {
+            let error: RuleErrorInfo = RuleErrorInfo::builder()
+                .rule("ReferentialActionPolicy")
+                .unwrap()
+                .object(object)
+                .unwrap()
+                .message(message)
+                .unwrap()
+                .resolution(resolution)
+                .unwrap()
+                .try_into()
+                .unwrap();
+            return Err(crate::error::Error::ForeignKey(
+                Box::new(foreign_key.clone()),
+                error.into(),
+            ));
+        }
+        Ok(())
+    }
+}
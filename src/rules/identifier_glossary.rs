@@ -0,0 +1,324 @@
+//! Submodule providing the `IdentifierGlossary` rule, which enforces that the
+//! words of identifiers are neither banned abbreviations nor vague words.
+
+use heck::ToSnakeCase;
+use sql_traits::traits::{ColumnLike, DatabaseLike, ForeignKeyLike};
+
+use crate::{
+    error::{ObjectRef, RuleErrorInfo},
+    rules::{
+        NamingCase,
+        named_objects::{identifier_constrainer, validate_table_objects},
+    },
+    traits::{ColumnRule, ForeignKeyRule, GenericConstrainer, TableRule},
+};
+
+/// Abbreviations banned by default, as pairs of abbreviation and expansion.
+const DEFAULT_ABBREVIATIONS: &[(&str, &str)] = &[
+    ("addr", "address"),
+    ("amt", "amount"),
+    ("attr", "attribute"),
+    ("cnt", "count"),
+    ("cust", "customer"),
+    ("dept", "department"),
+    ("desc", "description"),
+    ("descr", "description"),
+    ("mgr", "manager"),
+    ("msg", "message"),
+    ("nbr", "number"),
+    ("num", "number"),
+    ("pwd", "password"),
+    ("qty", "quantity"),
+    ("usr", "user"),
+    ("val", "value"),
+];
+
+/// Vague words discouraged by default.
+const DEFAULT_VAGUE_WORDS: &[&str] = &["data", "info", "misc", "stuff", "temp", "tmp"];
+
+/// Struct defining a rule that enforces that the names of tables, columns,
+/// foreign keys, check constraints and indices do not contain words of a
+/// configurable glossary.
+///
+/// 1. Banned abbreviations (by default e.g. `qty`, `usr` and `desc`) are
+///    reported with their expansion (`quantity`, `user` and `description`),
+///    and the resolution suggests the full identifier in the case of the
+///    original one, so that `usrName` becomes `userName`. Identifiers mixing
+///    cases get a `snake_case` suggestion.
+/// 2. Vague words (by default `data`, `info`, `misc`, `stuff`, `temp` and
+///    `tmp`) are reported, as they do not say anything about the content.
+///
+/// Identifiers are split into words as by the `snake_case` rules, so that
+/// `usrName` and `usr_name` both contain the word `usr`.
+///
+/// # Example
+///
+/// ```rust
+/// use sql_rules::prelude::*;
+/// use sqlparser::dialect::GenericDialect;
+///
+/// let constrainer: GenericConstrainer<ParserDB> = IdentifierGlossary::default().into();
+///
+/// // Invalid: banned abbreviation, suggesting `order_quantity`
+/// let invalid_schema = ParserDB::parse::<GenericDialect>("CREATE TABLE orders (order_qty INT);").unwrap();
+/// assert!(constrainer.validate_schema(&invalid_schema).is_err());
+///
+/// // Invalid: vague word
+/// let invalid_schema = ParserDB::parse::<GenericDialect>("CREATE TABLE misc_items (id INT);").unwrap();
+/// assert!(constrainer.validate_schema(&invalid_schema).is_err());
+///
+/// let valid_schema = ParserDB::parse::<GenericDialect>("CREATE TABLE orders (order_quantity INT);").unwrap();
+/// assert!(constrainer.validate_schema(&valid_schema).is_ok());
+///
+/// // Invalid: banned abbreviation, suggesting `userName` in the original case
+/// let invalid_schema = ParserDB::parse::<GenericDialect>("CREATE TABLE accounts (usrName TEXT);").unwrap();
+/// let error = constrainer.validate_schema(&invalid_schema).unwrap_err();
+/// assert!(error.to_string().contains("'userName'"));
+///
+/// // Custom glossary, allowing a default word
+/// let constrainer: GenericConstrainer<ParserDB> = IdentifierGlossary::default()
+///     .with_abbreviation("conc", "concentration")
+///     .with_vague_word("thing")
+///     .with_allowed_word("data")
+///     .into();
+/// let invalid_schema = ParserDB::parse::<GenericDialect>("CREATE TABLE samples (conc REAL);").unwrap();
+/// assert!(constrainer.validate_schema(&invalid_schema).is_err());
+/// let valid_schema = ParserDB::parse::<GenericDialect>("CREATE TABLE samples (raw_data TEXT);").unwrap();
+/// assert!(constrainer.validate_schema(&valid_schema).is_ok());
+/// ```
+pub struct IdentifierGlossary<DB> {
+    abbreviations: Vec<(String, String)>,
+    vague_words: Vec<String>,
+    allowed_words: Vec<String>,
+    _phantom: std::marker::PhantomData<DB>,
+}
+
+impl<DB> IdentifierGlossary<DB> {
+    /// Adds a banned abbreviation, with the expansion to suggest instead.
+    #[must_use]
+    pub fn with_abbreviation(
+        mut self,
+        abbreviation: impl Into<String>,
+        expansion: impl Into<String>,
+    ) -> Self {
+        self.abbreviations
+            .push((abbreviation.into(), expansion.into()));
+        self
+    }
+
+    /// Adds a discouraged vague word.
+    #[must_use]
+    pub fn with_vague_word(mut self, word: impl Into<String>) -> Self {
+        self.vague_words.push(word.into());
+        self
+    }
+
+    /// Adds a word which is allowed even if it is in the glossary.
+    #[must_use]
+    pub fn with_allowed_word(mut self, word: impl Into<String>) -> Self {
+        self.allowed_words.push(word.into());
+        self
+    }
+
+    /// Returns whether the provided word is allowed even if it is in the
+    /// glossary.
+    fn is_allowed(&self, word: &str) -> bool {
+        self.allowed_words
+            .iter()
+            .any(|allowed| allowed.eq_ignore_ascii_case(word))
+    }
+
+    /// Returns the expansion of the provided word, if it is a banned
+    /// abbreviation which is not allowed.
+    fn expansion(&self, word: &str) -> Option<&str> {
+        if self.is_allowed(word) {
+            return None;
+        }
+        self.abbreviations
+            .iter()
+            .find(|(abbreviation, _)| abbreviation.eq_ignore_ascii_case(word))
+            .map(|(_, expansion)| expansion.as_str())
+    }
+
+    /// Returns the error for an identifier of the provided kind containing
+    /// words of the glossary, if any.
+    fn error(
+        &self,
+        kind: &str,
+        identifier: &str,
+        object: impl FnOnce() -> ObjectRef,
+    ) -> Option<RuleErrorInfo> {
+        let snake_case_identifier = identifier.to_snake_case();
+        let words = snake_case_identifier
+            .split('_')
+            .filter(|word| !word.is_empty() && !self.is_allowed(word))
+            .collect::<Vec<_>>();
+        let abbreviations = words
+            .iter()
+            .filter_map(|word| {
+                self.expansion(word)
+                    .map(|expansion| format!("'{word}' for '{expansion}'"))
+            })
+            .collect::<Vec<_>>();
+        let vague_words = words
+            .iter()
+            .filter(|word| {
+                self.vague_words
+                    .iter()
+                    .any(|vague_word| vague_word.eq_ignore_ascii_case(word))
+            })
+            .map(|word| format!("'{word}'"))
+            .collect::<Vec<_>>();
+        if abbreviations.is_empty() && vague_words.is_empty() {
+            return None;
+        }
+
+        let subject = kind.to_lowercase();
+        let mut issues = Vec::new();
+        if !abbreviations.is_empty() {
+            issues.push(format!("the abbreviations {}", abbreviations.join(", ")));
+        }
+        if !vague_words.is_empty() {
+            issues.push(format!("the vague words {}", vague_words.join(", ")));
+        }
+        let vague_words = vague_words.join(", ");
+        let resolution = if abbreviations.is_empty() {
+            format!(
+                "Replace the vague words {vague_words} in the {subject} '{identifier}' with more specific ones."
+            )
+        } else {
+            let suggestion = snake_case_identifier
+                .split('_')
+                .map(|word| self.expansion(word).unwrap_or(word))
+                .collect::<Vec<_>>()
+                .join("_");
+            let suggestion = [
+                NamingCase::SnakeCase,
+                NamingCase::CamelCase,
+                NamingCase::PascalCase,
+                NamingCase::ScreamingSnakeCase,
+            ]
+            .into_iter()
+            .find(|case| case.matches(identifier))
+            .and_then(|case| case.suggestion(&suggestion))
+            .unwrap_or(suggestion);
+            if vague_words.is_empty() {
+                format!("Rename the {subject} '{identifier}' to '{suggestion}'.")
+            } else {
+                format!(
+                    "Rename the {subject} '{identifier}' to '{suggestion}', replacing the vague words {vague_words} with more specific ones."
+                )
+            }
+        };
+        Some(
+            RuleErrorInfo::builder()
+                .rule("IdentifierGlossary")
+                .unwrap()
+                .object(object())
+                .unwrap()
+                .message(format!(
+                    "{kind} name '{identifier}' contains {}.",
+                    issues.join(" and ")
+                ))
+                .unwrap()
+                .resolution(resolution)
+                .unwrap()
+                .try_into()
+                .unwrap(),
+        )
+    }
+}
+
+impl<DB> Default for IdentifierGlossary<DB> {
+    fn default() -> Self {
+        Self {
+            abbreviations: DEFAULT_ABBREVIATIONS
+                .iter()
+                .map(|(abbreviation, expansion)| {
+                    ((*abbreviation).to_owned(), (*expansion).to_owned())
+                })
+                .collect(),
+            vague_words: DEFAULT_VAGUE_WORDS
+                .iter()
+                .map(|word| (*word).to_owned())
+                .collect(),
+            allowed_words: Vec::new(),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<DB> Clone for IdentifierGlossary<DB> {
+    fn clone(&self) -> Self {
+        Self {
+            abbreviations: self.abbreviations.clone(),
+            vague_words: self.vague_words.clone(),
+            allowed_words: self.allowed_words.clone(),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<DB: DatabaseLike + 'static> From<IdentifierGlossary<DB>> for GenericConstrainer<DB> {
+    fn from(rule: IdentifierGlossary<DB>) -> Self {
+        identifier_constrainer(rule)
+    }
+}
+
+impl<DB: DatabaseLike> TableRule for IdentifierGlossary<DB> {
+    type Database = DB;
+
+    fn validate_table(
+        &self,
+        database: &Self::Database,
+        table: &<Self::Database as DatabaseLike>::Table,
+    ) -> Result<(), crate::error::Error<DB>> {
+        validate_table_objects(database, table, |object| {
+            self.error(object.kind(), object.name()?, || {
+                object.object_ref(database)
+            })
+        })
+    }
+}
+
+impl<DB: DatabaseLike> ColumnRule for IdentifierGlossary<DB> {
+    type Database = DB;
+
+    fn validate_column(
+        &self,
+        database: &Self::Database,
+        column: &<Self::Database as DatabaseLike>::Column,
+    ) -> Result<(), crate::error::Error<DB>> {
+        if let Some(error) = self.error("Column", column.column_name(), || {
+            ObjectRef::column(database, column)
+        }) {
+            return Err(crate::error::Error::Column(
+                Box::new(column.clone()),
+                error.into(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl<DB: DatabaseLike> ForeignKeyRule for IdentifierGlossary<DB> {
+    type Database = DB;
+
+    fn validate_foreign_key(
+        &self,
+        database: &Self::Database,
+        foreign_key: &<Self::Database as DatabaseLike>::ForeignKey,
+    ) -> Result<(), crate::error::Error<DB>> {
+        if let Some(name) = foreign_key.foreign_key_name()
+            && let Some(error) = self.error("Foreign key", name, || {
+                ObjectRef::foreign_key(database, foreign_key)
+            })
+        {
+            return Err(crate::error::Error::ForeignKey(
+                Box::new(foreign_key.clone()),
+                error.into(),
+            ));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,246 @@
+//! Submodule providing the dictionary of exceptions used by the rules checking
+//! the grammatical number of names, such as
+//! [`PluralTableName`](crate::rules::PluralTableName) and
+//! [`SingularColumnName`](crate::rules::SingularColumnName).
+
+use inflection_rs::inflection::{pluralize, singularize};
+
+/// Scientific words whose singular and plural forms are the same, or which
+/// are not counted, in lexicographic order.
+const SCIENTIFIC_UNCOUNTABLE_WORDS: &[&str] = &[
+    "biomass",
+    "chemistry",
+    "chromatography",
+    "data",
+    "equipment",
+    "evidence",
+    "genomics",
+    "information",
+    "metabolomics",
+    "metadata",
+    "microscopy",
+    "proteomics",
+    "research",
+    "series",
+    "software",
+    "species",
+    "spectrometry",
+    "spectroscopy",
+];
+
+/// Scientific words with an irregular plural, as pairs of singular and plural
+/// forms.
+const SCIENTIFIC_IRREGULAR_WORDS: &[(&str, &str)] = &[
+    ("alga", "algae"),
+    ("analysis", "analyses"),
+    ("appendix", "appendices"),
+    ("bacterium", "bacteria"),
+    ("census", "censuses"),
+    ("criterion", "criteria"),
+    ("fungus", "fungi"),
+    ("genus", "genera"),
+    ("hypothesis", "hypotheses"),
+    ("index", "indices"),
+    ("locus", "loci"),
+    ("matrix", "matrices"),
+    ("medium", "media"),
+    ("mitochondrion", "mitochondria"),
+    ("nucleus", "nuclei"),
+    ("phenomenon", "phenomena"),
+    ("radius", "radii"),
+    ("spectrum", "spectra"),
+    ("status", "statuses"),
+    ("stimulus", "stimuli"),
+    ("stratum", "strata"),
+    ("synthesis", "syntheses"),
+    ("taxon", "taxa"),
+    ("vertex", "vertices"),
+    ("virus", "viruses"),
+];
+
+#[derive(Debug, Clone)]
+/// Dictionary of the exceptions to the inflection rules of English, used to
+/// determine the singular and plural forms of the last segment of names.
+///
+/// The default dictionary contains a built-in scientific vocabulary (e.g.
+/// `metadata` and `spectrometry` are uncountable, `taxon` and `spectrum`
+/// pluralize to `taxa` and `spectra`), which can be extended with
+/// uncountable and irregular words. By default, the last segment of a name is
+/// the part after its last underscore: configured compound suffixes, such as
+/// `mother_in_law`, are instead treated as a single segment.
+///
+/// # Example
+///
+/// ```rust
+/// use sql_rules::prelude::*;
+///
+/// let dictionary = InflectionDictionary::default();
+/// assert_eq!(dictionary.pluralize("taxon"), "taxa");
+/// assert_eq!(dictionary.singularize("spectra"), "spectrum");
+/// assert_eq!(dictionary.singularize("metadata"), "metadata");
+/// assert_eq!(dictionary.last_segment("sample_metadata"), "metadata");
+/// assert_eq!(dictionary.pluralize_name("mass_spectrum"), "mass_spectra");
+/// assert_eq!(dictionary.singularize_name("plant_taxa"), "plant_taxon");
+///
+/// let dictionary = InflectionDictionary::empty()
+///     .with_uncountable("sheep")
+///     .with_irregular("mother_in_law", "mothers_in_law")
+///     .with_compound_suffix("mother_in_law")
+///     .with_compound_suffix("mothers_in_law");
+/// assert_eq!(dictionary.pluralize("sheep"), "sheep");
+/// assert_eq!(dictionary.last_segment("spouse_mother_in_law"), "mother_in_law");
+/// assert_eq!(dictionary.pluralize("mother_in_law"), "mothers_in_law");
+/// ```
+pub struct InflectionDictionary {
+    uncountable_words: Vec<String>,
+    irregular_words: Vec<(String, String)>,
+    compound_suffixes: Vec<String>,
+}
+
+impl InflectionDictionary {
+    /// Creates a dictionary without any exception, not even the built-in
+    /// scientific vocabulary.
+    #[must_use]
+    pub fn empty() -> Self {
+        Self {
+            uncountable_words: Vec::new(),
+            irregular_words: Vec::new(),
+            compound_suffixes: Vec::new(),
+        }
+    }
+
+    /// Adds a word whose singular and plural forms are the same.
+    #[must_use]
+    pub fn with_uncountable(mut self, word: impl Into<String>) -> Self {
+        self.uncountable_words.push(word.into());
+        self
+    }
+
+    /// Adds a word with an irregular plural, which takes precedence over the
+    /// built-in irregular words.
+    #[must_use]
+    pub fn with_irregular(
+        mut self,
+        singular: impl Into<String>,
+        plural: impl Into<String>,
+    ) -> Self {
+        self.irregular_words
+            .insert(0, (singular.into(), plural.into()));
+        self
+    }
+
+    /// Adds a suffix made of several underscore-separated words which is
+    /// treated as the single last segment of the names ending with it.
+    #[must_use]
+    pub fn with_compound_suffix(mut self, suffix: impl Into<String>) -> Self {
+        self.compound_suffixes.push(suffix.into());
+        self
+    }
+
+    /// Returns the last segment of the provided name, which is the longest
+    /// compound suffix it ends with, if any, or the part after its last
+    /// underscore.
+    #[must_use]
+    pub fn last_segment<'a>(&self, name: &'a str) -> &'a str {
+        let lowercase_name = name.to_ascii_lowercase();
+        self.compound_suffixes
+            .iter()
+            .map(|suffix| suffix.to_ascii_lowercase())
+            .filter(|suffix| {
+                lowercase_name
+                    .strip_suffix(suffix.as_str())
+                    .is_some_and(|prefix| prefix.is_empty() || prefix.ends_with('_'))
+            })
+            .map(|suffix| suffix.len())
+            .max()
+            .map_or_else(
+                || name.split('_').next_back().unwrap_or(name),
+                |length| &name[name.len() - length..],
+            )
+    }
+
+    /// Returns the plural form of the provided segment.
+    #[must_use]
+    pub fn pluralize(&self, segment: &str) -> String {
+        self.inflect(segment, true)
+    }
+
+    /// Returns the singular form of the provided segment.
+    #[must_use]
+    pub fn singularize(&self, segment: &str) -> String {
+        self.inflect(segment, false)
+    }
+
+    /// Returns the provided name with its last segment pluralized.
+    #[must_use]
+    pub fn pluralize_name(&self, name: &str) -> String {
+        let last_segment = self.last_segment(name);
+        format!(
+            "{}{}",
+            &name[..name.len() - last_segment.len()],
+            self.pluralize(last_segment)
+        )
+    }
+
+    /// Returns the provided name with its last segment singularized.
+    #[must_use]
+    pub fn singularize_name(&self, name: &str) -> String {
+        let last_segment = self.last_segment(name);
+        format!(
+            "{}{}",
+            &name[..name.len() - last_segment.len()],
+            self.singularize(last_segment)
+        )
+    }
+
+    /// Returns the plural or singular form of the provided segment, looking
+    /// up the dictionary before falling back to the inflection rules. Of a
+    /// compound segment, only the last word is inflected when the segment is
+    /// not in the dictionary.
+    fn inflect(&self, segment: &str, plural: bool) -> String {
+        if self
+            .uncountable_words
+            .iter()
+            .any(|word| word.eq_ignore_ascii_case(segment))
+        {
+            return segment.to_owned();
+        }
+        for (singular_form, plural_form) in &self.irregular_words {
+            let (source, target) = if plural {
+                (singular_form, plural_form)
+            } else {
+                (plural_form, singular_form)
+            };
+            if source.eq_ignore_ascii_case(segment) {
+                return target.clone();
+            }
+            if target.eq_ignore_ascii_case(segment) {
+                return segment.to_owned();
+            }
+        }
+        if let Some((prefix, word)) = segment.rsplit_once('_') {
+            return format!("{prefix}_{}", self.inflect(word, plural));
+        }
+        if plural {
+            pluralize(segment)
+        } else {
+            singularize(segment)
+        }
+    }
+}
+
+impl Default for InflectionDictionary {
+    fn default() -> Self {
+        Self {
+            uncountable_words: SCIENTIFIC_UNCOUNTABLE_WORDS
+                .iter()
+                .map(|word| (*word).to_owned())
+                .collect(),
+            irregular_words: SCIENTIFIC_IRREGULAR_WORDS
+                .iter()
+                .map(|(singular, plural)| ((*singular).to_owned(), (*plural).to_owned()))
+                .collect(),
+            compound_suffixes: Vec::new(),
+        }
+    }
+}
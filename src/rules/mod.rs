@@ -6,4 +6,30 @@ mod column_rules;
 pub use column_rules::*;
 mod foreign_key_rules;
 pub use foreign_key_rules::*;
+mod database_rules;
+pub use database_rules::*;
+mod constraint_naming_template;
+pub use constraint_naming_template::ConstraintNamingTemplate;
+mod explicitly_named_constraint;
+pub use explicitly_named_constraint::ExplicitlyNamedConstraint;
+mod identifier_glossary;
+pub use identifier_glossary::IdentifierGlossary;
+mod inflection_dictionary;
+pub use inflection_dictionary::InflectionDictionary;
+mod naming_case;
+pub use naming_case::NamingCase;
+mod naming_convention;
+pub use naming_convention::NamingConvention;
+mod no_reserved_identifier;
+pub use no_reserved_identifier::NoReservedIdentifier;
+mod no_sql_reserved_keyword;
+pub use no_sql_reserved_keyword::NoSqlReservedKeyword;
+mod reserved_words;
+pub use reserved_words::{ReservedWords, RustEdition};
+mod sql_dialect;
+pub use sql_dialect::SqlDialect;
+pub(crate) mod data_types;
+pub(crate) mod expression_analysis;
+pub(crate) mod name_pattern;
+pub(crate) mod named_objects;
 pub mod rust_keywords;
@@ -0,0 +1,27 @@
+//! Submodule providing a minimal matcher of names against glob patterns, used
+//! by the rules configured with name patterns such as `*_count`.
+
+/// Returns whether the provided name matches the provided pattern, where `*`
+/// matches any (possibly empty) sequence of characters. The comparison is
+/// ASCII case-insensitive.
+pub(crate) fn matches_glob(pattern: &str, name: &str) -> bool {
+    let pattern = pattern.to_ascii_lowercase();
+    let name = name.to_ascii_lowercase();
+    let mut segments = pattern.split('*');
+    let Some(mut rest) = name.strip_prefix(segments.next().unwrap_or_default()) else {
+        return false;
+    };
+    let segments = segments.collect::<Vec<_>>();
+    let Some((last, middle)) = segments.split_last() else {
+        return rest.is_empty();
+    };
+    // Matching each intermediate segment at its leftmost occurrence leaves
+    // the most room for the following ones.
+    for segment in middle {
+        let Some(position) = rest.find(segment) else {
+            return false;
+        };
+        rest = &rest[position + segment.len()..];
+    }
+    rest.ends_with(last)
+}
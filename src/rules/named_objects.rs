@@ -0,0 +1,130 @@
+//! Submodule providing the objects of a table which may carry a name, shared
+//! by the rules checking identifiers such as
+//! [`NoSqlReservedKeyword`](crate::rules::NoSqlReservedKeyword), together
+//! with the helpers converting such rules into a [`GenericConstrainer`].
+
+use sql_traits::traits::{CheckConstraintLike, DatabaseLike, IndexLike, TableLike};
+
+use crate::{
+    error::{Error, ObjectRef, RuleErrorInfo},
+    traits::{ColumnRule, Constrainer, ForeignKeyRule, GenericConstrainer, TableRule},
+};
+
+/// An object of a table which may carry a name: the table itself, one of its
+/// check constraints or one of its indices.
+pub(crate) enum TableObject<'db, DB: DatabaseLike> {
+    /// The table itself.
+    Table(&'db DB::Table),
+    /// A check constraint of the table.
+    CheckConstraint(&'db DB::Table, &'db DB::CheckConstraint),
+    /// An index of the table which is not unique.
+    Index(&'db DB::Table, &'db DB::Index),
+    /// A unique index or constraint of the table.
+    UniqueIndex(&'db DB::Table, &'db DB::UniqueIndex),
+}
+
+impl<'db, DB: DatabaseLike> TableObject<'db, DB> {
+    /// Returns the provided table, followed by its check constraints, its
+    /// indices and its unique indices.
+    pub(crate) fn of(database: &'db DB, table: &'db DB::Table) -> impl Iterator<Item = Self> {
+        std::iter::once(TableObject::Table(table))
+            .chain(
+                table
+                    .check_constraints(database)
+                    .map(move |cc| TableObject::CheckConstraint(table, cc)),
+            )
+            .chain(
+                table
+                    .indices(database)
+                    .map(move |index| TableObject::Index(table, index)),
+            )
+            .chain(
+                table
+                    .unique_indices(database)
+                    .map(move |index| TableObject::UniqueIndex(table, index)),
+            )
+    }
+
+    /// Returns the kind of the object, as used at the start of error
+    /// messages.
+    pub(crate) fn kind(&self) -> &'static str {
+        match self {
+            TableObject::Table(_) => "Table",
+            TableObject::CheckConstraint(..) => "Check constraint",
+            TableObject::Index(..) => "Index",
+            TableObject::UniqueIndex(..) => "Unique constraint",
+        }
+    }
+
+    /// Returns the name of the object, if it is named.
+    pub(crate) fn name(&self) -> Option<&'db str> {
+        match *self {
+            TableObject::Table(table) => Some(table.table_name()),
+            TableObject::CheckConstraint(_, cc) => cc.check_constraint_name(),
+            TableObject::Index(_, index) => index.index_name(),
+            TableObject::UniqueIndex(_, index) => index.index_name(),
+        }
+    }
+
+    /// Returns the reference to the object used in error reports.
+    pub(crate) fn object_ref(&self, database: &DB) -> ObjectRef {
+        match *self {
+            TableObject::Table(table) => ObjectRef::table(table),
+            TableObject::CheckConstraint(table, cc) => {
+                ObjectRef::check_constraint(table, self.name(), cc.expression(database))
+            }
+            TableObject::Index(table, index) => {
+                ObjectRef::index(table, self.name(), index.expression(database))
+            }
+            TableObject::UniqueIndex(table, index) => {
+                ObjectRef::index(table, self.name(), index.expression(database))
+            }
+        }
+    }
+}
+
+/// Validates the objects of the provided table with the provided check,
+/// reporting the first error it returns as a violation of a table rule.
+///
+/// # Errors
+///
+/// Returns an error if the check fails for any object of the table.
+pub(crate) fn validate_table_objects<'db, DB: DatabaseLike>(
+    database: &'db DB,
+    table: &'db DB::Table,
+    check: impl FnMut(TableObject<'db, DB>) -> Option<RuleErrorInfo>,
+) -> Result<(), Error<DB>> {
+    match TableObject::of(database, table).find_map(check) {
+        Some(error) => Err(Error::Table(Box::new(table.clone()), error.into())),
+        None => Ok(()),
+    }
+}
+
+/// Returns a [`GenericConstrainer`] with the provided rule registered both as
+/// a table and as a foreign key rule.
+pub(crate) fn table_and_foreign_key_constrainer<DB, R>(rule: R) -> GenericConstrainer<DB>
+where
+    DB: DatabaseLike + 'static,
+    R: TableRule<Database = DB> + ForeignKeyRule<Database = DB> + Clone + 'static,
+{
+    let mut constrainer = GenericConstrainer::default();
+    constrainer.register_table_rule(Box::new(rule.clone()));
+    constrainer.register_foreign_key_rule(Box::new(rule));
+    constrainer
+}
+
+/// Returns a [`GenericConstrainer`] with the provided rule registered as a
+/// table, column and foreign key rule.
+pub(crate) fn identifier_constrainer<DB, R>(rule: R) -> GenericConstrainer<DB>
+where
+    DB: DatabaseLike + 'static,
+    R: TableRule<Database = DB>
+        + ColumnRule<Database = DB>
+        + ForeignKeyRule<Database = DB>
+        + Clone
+        + 'static,
+{
+    let mut constrainer = table_and_foreign_key_constrainer(rule.clone());
+    constrainer.register_column_rule(Box::new(rule));
+    constrainer
+}
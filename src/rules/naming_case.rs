@@ -0,0 +1,94 @@
+//! Submodule providing the naming cases supported by the
+//! [`NamingConvention`](crate::rules::NamingConvention) rule.
+
+use std::fmt::Display;
+
+use heck::{ToLowerCamelCase, ToShoutySnakeCase, ToSnakeCase, ToUpperCamelCase};
+use regex::Regex;
+
+#[derive(Debug, Clone, Default)]
+/// Naming cases identifiers may be required to follow.
+///
+/// # Example
+///
+/// ```rust
+/// use sql_rules::prelude::*;
+///
+/// assert!(NamingCase::SnakeCase.matches("user_accounts"));
+/// assert!(!NamingCase::SnakeCase.matches("UserAccounts"));
+/// assert!(NamingCase::CamelCase.matches("userAccounts"));
+/// assert!(NamingCase::PascalCase.matches("UserAccounts"));
+/// assert!(NamingCase::ScreamingSnakeCase.matches("USER_ACCOUNTS"));
+/// assert_eq!(
+///     NamingCase::PascalCase.suggestion("user_accounts").as_deref(),
+///     Some("UserAccounts")
+/// );
+///
+/// let custom = NamingCase::custom("tbl_[a-z_]+").unwrap();
+/// assert!(custom.matches("tbl_users"));
+/// assert!(!custom.matches("users"));
+/// assert!(custom.suggestion("users").is_none());
+/// assert!(NamingCase::custom("(").is_err());
+/// ```
+pub enum NamingCase {
+    /// `snake_case`, e.g. `user_accounts`.
+    #[default]
+    SnakeCase,
+    /// `camelCase`, e.g. `userAccounts`.
+    CamelCase,
+    /// `PascalCase`, e.g. `UserAccounts`.
+    PascalCase,
+    /// `SCREAMING_SNAKE_CASE`, e.g. `USER_ACCOUNTS`.
+    ScreamingSnakeCase,
+    /// A custom regular expression, which must match the whole name.
+    Custom(Regex),
+}
+
+impl NamingCase {
+    /// Creates a custom naming case from the provided regular expression,
+    /// which must match the whole name.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the provided pattern is not a valid regular
+    /// expression.
+    pub fn custom(pattern: &str) -> Result<Self, regex::Error> {
+        Ok(Self::Custom(Regex::new(&format!("^(?:{pattern})$"))?))
+    }
+
+    /// Returns the provided name converted to the naming case, if the naming
+    /// case defines a conversion.
+    #[must_use]
+    pub fn suggestion(&self, name: &str) -> Option<String> {
+        match self {
+            NamingCase::SnakeCase => Some(name.to_snake_case()),
+            NamingCase::CamelCase => Some(name.to_lower_camel_case()),
+            NamingCase::PascalCase => Some(name.to_upper_camel_case()),
+            NamingCase::ScreamingSnakeCase => Some(name.to_shouty_snake_case()),
+            NamingCase::Custom(_) => None,
+        }
+    }
+
+    /// Returns whether the provided name follows the naming case.
+    #[must_use]
+    pub fn matches(&self, name: &str) -> bool {
+        match self {
+            NamingCase::Custom(regex) => regex.is_match(name),
+            _ => self
+                .suggestion(name)
+                .is_some_and(|expected| expected == name),
+        }
+    }
+}
+
+impl Display for NamingCase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NamingCase::SnakeCase => write!(f, "snake_case"),
+            NamingCase::CamelCase => write!(f, "camelCase"),
+            NamingCase::PascalCase => write!(f, "PascalCase"),
+            NamingCase::ScreamingSnakeCase => write!(f, "SCREAMING_SNAKE_CASE"),
+            NamingCase::Custom(regex) => write!(f, "the pattern `{regex}`"),
+        }
+    }
+}
@@ -0,0 +1,348 @@
+//! Submodule providing the `NamingConvention` rule, which enforces that
+//! identifiers follow the naming case configured for their kind of object.
+
+use sql_traits::traits::{ColumnLike, DatabaseLike, ForeignKeyLike, TableLike};
+
+use crate::{
+    error::{ObjectRef, RuleErrorInfo},
+    rules::{
+        NamingCase,
+        named_objects::{TableObject, identifier_constrainer, validate_table_objects},
+    },
+    traits::{ColumnRule, ForeignKeyRule, GenericConstrainer, TableRule},
+};
+
+/// Struct defining a rule that enforces that the names of tables, columns,
+/// foreign keys, check constraints and indices follow the [`NamingCase`]
+/// configured for their kind of object.
+///
+/// By default, every kind of object must follow `snake_case`. Legacy names
+/// can be allowed per kind of object, either as `name` or as `table.name` to
+/// allow the object of a single table, and are matched ignoring ASCII case.
+///
+/// # Example
+///
+/// ```rust
+/// use sql_rules::prelude::*;
+/// use sqlparser::dialect::GenericDialect;
+///
+/// let constrainer: GenericConstrainer<ParserDB> = NamingConvention::default().into();
+///
+/// let invalid_schema = ParserDB::parse::<GenericDialect>("CREATE TABLE UserAccounts (id INT);").unwrap();
+/// assert!(constrainer.validate_schema(&invalid_schema).is_err());
+///
+/// let invalid_schema = ParserDB::parse::<GenericDialect>("CREATE TABLE user_accounts (userName TEXT);").unwrap();
+/// assert!(constrainer.validate_schema(&invalid_schema).is_err());
+///
+/// let valid_schema = ParserDB::parse::<GenericDialect>("CREATE TABLE user_accounts (user_name TEXT);").unwrap();
+/// assert!(constrainer.validate_schema(&valid_schema).is_ok());
+///
+/// // PascalCase tables with camelCase columns, allowing a legacy column
+/// let constrainer: GenericConstrainer<ParserDB> = NamingConvention::default()
+///     .with_table_case(NamingCase::PascalCase)
+///     .with_column_case(NamingCase::CamelCase)
+///     .with_allowed_column_name("useraccounts.LEGACY_ID")
+///     .into();
+/// let valid_schema = ParserDB::parse::<GenericDialect>(
+///     "CREATE TABLE UserAccounts (userName TEXT, legacy_id INT);",
+/// )
+/// .unwrap();
+/// assert!(constrainer.validate_schema(&valid_schema).is_ok());
+///
+/// // Allowing a column name does not allow a table with the same name
+/// let invalid_schema = ParserDB::parse::<GenericDialect>(
+///     "CREATE TABLE legacy_id (id INT);",
+/// )
+/// .unwrap();
+/// assert!(constrainer.validate_schema(&invalid_schema).is_err());
+///
+/// // Custom regular expressions
+/// let constrainer: GenericConstrainer<ParserDB> = NamingConvention::default()
+///     .with_table_case(NamingCase::custom("tbl_[a-z_]+").unwrap())
+///     .into();
+/// let invalid_schema = ParserDB::parse::<GenericDialect>("CREATE TABLE users (id INT);").unwrap();
+/// assert!(constrainer.validate_schema(&invalid_schema).is_err());
+/// ```
+pub struct NamingConvention<DB> {
+    table_case: NamingCase,
+    column_case: NamingCase,
+    foreign_key_case: NamingCase,
+    check_constraint_case: NamingCase,
+    index_case: NamingCase,
+    allowed_table_names: Vec<String>,
+    allowed_column_names: Vec<String>,
+    allowed_foreign_key_names: Vec<String>,
+    allowed_check_constraint_names: Vec<String>,
+    allowed_index_names: Vec<String>,
+    _phantom: std::marker::PhantomData<DB>,
+}
+
+impl<DB> NamingConvention<DB> {
+    /// Sets the naming case of every kind of object.
+    #[must_use]
+    pub fn with_case(self, case: NamingCase) -> Self {
+        self.with_table_case(case.clone())
+            .with_column_case(case.clone())
+            .with_foreign_key_case(case.clone())
+            .with_check_constraint_case(case.clone())
+            .with_index_case(case)
+    }
+
+    /// Sets the naming case of tables.
+    #[must_use]
+    pub fn with_table_case(mut self, case: NamingCase) -> Self {
+        self.table_case = case;
+        self
+    }
+
+    /// Sets the naming case of columns.
+    #[must_use]
+    pub fn with_column_case(mut self, case: NamingCase) -> Self {
+        self.column_case = case;
+        self
+    }
+
+    /// Sets the naming case of foreign keys.
+    #[must_use]
+    pub fn with_foreign_key_case(mut self, case: NamingCase) -> Self {
+        self.foreign_key_case = case;
+        self
+    }
+
+    /// Sets the naming case of check constraints.
+    #[must_use]
+    pub fn with_check_constraint_case(mut self, case: NamingCase) -> Self {
+        self.check_constraint_case = case;
+        self
+    }
+
+    /// Sets the naming case of indices and unique constraints.
+    #[must_use]
+    pub fn with_index_case(mut self, case: NamingCase) -> Self {
+        self.index_case = case;
+        self
+    }
+
+    /// Adds a name which is allowed regardless of the naming case for every
+    /// kind of object, either as `name` or as `table.name`.
+    #[must_use]
+    pub fn with_allowed_name(self, name: impl Into<String>) -> Self {
+        let name = name.into();
+        self.with_allowed_table_name(name.clone())
+            .with_allowed_column_name(name.clone())
+            .with_allowed_foreign_key_name(name.clone())
+            .with_allowed_check_constraint_name(name.clone())
+            .with_allowed_index_name(name)
+    }
+
+    /// Adds a table name which is allowed regardless of the naming case.
+    #[must_use]
+    pub fn with_allowed_table_name(mut self, name: impl Into<String>) -> Self {
+        self.allowed_table_names.push(name.into());
+        self
+    }
+
+    /// Adds a column name which is allowed regardless of the naming case,
+    /// either as `column` or as `table.column`.
+    #[must_use]
+    pub fn with_allowed_column_name(mut self, name: impl Into<String>) -> Self {
+        self.allowed_column_names.push(name.into());
+        self
+    }
+
+    /// Adds a foreign key name which is allowed regardless of the naming
+    /// case, either as `name` or as `table.name`.
+    #[must_use]
+    pub fn with_allowed_foreign_key_name(mut self, name: impl Into<String>) -> Self {
+        self.allowed_foreign_key_names.push(name.into());
+        self
+    }
+
+    /// Adds a check constraint name which is allowed regardless of the naming
+    /// case, either as `name` or as `table.name`.
+    #[must_use]
+    pub fn with_allowed_check_constraint_name(mut self, name: impl Into<String>) -> Self {
+        self.allowed_check_constraint_names.push(name.into());
+        self
+    }
+
+    /// Adds an index or unique constraint name which is allowed regardless of
+    /// the naming case, either as `name` or as `table.name`.
+    #[must_use]
+    pub fn with_allowed_index_name(mut self, name: impl Into<String>) -> Self {
+        self.allowed_index_names.push(name.into());
+        self
+    }
+
+    /// Returns whether the provided name of an object of the provided table
+    /// is among the provided allowed names.
+    fn is_allowed(allowed_names: &[String], table_name: &str, name: &str) -> bool {
+        allowed_names
+            .iter()
+            .any(|allowed| match allowed.split_once('.') {
+                Some((table, allowed)) => {
+                    table.eq_ignore_ascii_case(table_name) && allowed.eq_ignore_ascii_case(name)
+                }
+                None => allowed.eq_ignore_ascii_case(name),
+            })
+    }
+
+    /// Returns the error for an object of the provided kind whose name does
+    /// not follow the provided naming case, if any.
+    fn error(
+        kind: &str,
+        table_name: &str,
+        name: &str,
+        case: &NamingCase,
+        allowed_names: &[String],
+        object: impl FnOnce() -> ObjectRef,
+    ) -> Option<RuleErrorInfo> {
+        if case.matches(name) || Self::is_allowed(allowed_names, table_name, name) {
+            return None;
+        }
+        let subject = kind.to_lowercase();
+        let resolution = match case.suggestion(name) {
+            Some(expected) => format!("Rename the {subject} '{name}' to '{expected}'."),
+            None => format!("Rename the {subject} '{name}' to follow {case}."),
+        };
+        Some(
+            RuleErrorInfo::builder()
+                .rule("NamingConvention")
+                .unwrap()
+                .object(object())
+                .unwrap()
+                .message(format!("{kind} name '{name}' does not follow {case}."))
+                .unwrap()
+                .resolution(resolution)
+                .unwrap()
+                .try_into()
+                .unwrap(),
+        )
+    }
+}
+
+impl<DB> Default for NamingConvention<DB> {
+    fn default() -> Self {
+        Self {
+            table_case: NamingCase::default(),
+            column_case: NamingCase::default(),
+            foreign_key_case: NamingCase::default(),
+            check_constraint_case: NamingCase::default(),
+            index_case: NamingCase::default(),
+            allowed_table_names: Vec::new(),
+            allowed_column_names: Vec::new(),
+            allowed_foreign_key_names: Vec::new(),
+            allowed_check_constraint_names: Vec::new(),
+            allowed_index_names: Vec::new(),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<DB> Clone for NamingConvention<DB> {
+    fn clone(&self) -> Self {
+        Self {
+            table_case: self.table_case.clone(),
+            column_case: self.column_case.clone(),
+            foreign_key_case: self.foreign_key_case.clone(),
+            check_constraint_case: self.check_constraint_case.clone(),
+            index_case: self.index_case.clone(),
+            allowed_table_names: self.allowed_table_names.clone(),
+            allowed_column_names: self.allowed_column_names.clone(),
+            allowed_foreign_key_names: self.allowed_foreign_key_names.clone(),
+            allowed_check_constraint_names: self.allowed_check_constraint_names.clone(),
+            allowed_index_names: self.allowed_index_names.clone(),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<DB: DatabaseLike + 'static> From<NamingConvention<DB>> for GenericConstrainer<DB> {
+    fn from(rule: NamingConvention<DB>) -> Self {
+        identifier_constrainer(rule)
+    }
+}
+
+impl<DB: DatabaseLike> TableRule for NamingConvention<DB> {
+    type Database = DB;
+
+    fn validate_table(
+        &self,
+        database: &Self::Database,
+        table: &<Self::Database as DatabaseLike>::Table,
+    ) -> Result<(), crate::error::Error<DB>> {
+        let table_name = table.table_name();
+        validate_table_objects(database, table, |object| {
+            let (case, allowed_names) = match object {
+                TableObject::Table(_) => (&self.table_case, &self.allowed_table_names),
+                TableObject::CheckConstraint(..) => (
+                    &self.check_constraint_case,
+                    &self.allowed_check_constraint_names,
+                ),
+                TableObject::Index(..) | TableObject::UniqueIndex(..) => {
+                    (&self.index_case, &self.allowed_index_names)
+                }
+            };
+            Self::error(
+                object.kind(),
+                table_name,
+                object.name()?,
+                case,
+                allowed_names,
+                || object.object_ref(database),
+            )
+        })
+    }
+}
+
+impl<DB: DatabaseLike> ColumnRule for NamingConvention<DB> {
+    type Database = DB;
+
+    fn validate_column(
+        &self,
+        database: &Self::Database,
+        column: &<Self::Database as DatabaseLike>::Column,
+    ) -> Result<(), crate::error::Error<DB>> {
+        if let Some(error) = Self::error(
+            "Column",
+            column.table(database).table_name(),
+            column.column_name(),
+            &self.column_case,
+            &self.allowed_column_names,
+            || ObjectRef::column(database, column),
+        ) {
+            return Err(crate::error::Error::Column(
+                Box::new(column.clone()),
+                error.into(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl<DB: DatabaseLike> ForeignKeyRule for NamingConvention<DB> {
+    type Database = DB;
+
+    fn validate_foreign_key(
+        &self,
+        database: &Self::Database,
+        foreign_key: &<Self::Database as DatabaseLike>::ForeignKey,
+    ) -> Result<(), crate::error::Error<DB>> {
+        if let Some(name) = foreign_key.foreign_key_name()
+            && let Some(error) = Self::error(
+                "Foreign key",
+                foreign_key.host_table(database).table_name(),
+                name,
+                &self.foreign_key_case,
+                &self.allowed_foreign_key_names,
+                || ObjectRef::foreign_key(database, foreign_key),
+            )
+        {
+            return Err(crate::error::Error::ForeignKey(
+                Box::new(foreign_key.clone()),
+                error.into(),
+            ));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,192 @@
+//! Submodule providing the `NoReservedIdentifier` rule, which enforces that
+//! table, column and foreign key names are not reserved words in any of the
+//! configured target languages.
+
+use sql_traits::traits::{ColumnLike, DatabaseLike, ForeignKeyLike, TableLike};
+
+use crate::{
+    error::{ObjectRef, RuleErrorInfo},
+    rules::{ReservedWords, RustEdition, named_objects::identifier_constrainer},
+    traits::{ColumnRule, ForeignKeyRule, GenericConstrainer, TableRule},
+};
+
+/// Struct defining a rule that enforces that table, column and foreign key
+/// names are not reserved words in any of the configured target languages.
+///
+/// By default, only the Rust 2021 keywords are reserved, which is how the
+/// [`DefaultConstrainer`](crate::traits::DefaultConstrainer) registers it.
+///
+/// # Example
+///
+/// ```rust
+/// use sql_rules::prelude::*;
+/// use sqlparser::dialect::GenericDialect;
+///
+/// let constrainer: GenericConstrainer<ParserDB> = NoReservedIdentifier::default().into();
+///
+/// let invalid_schema = ParserDB::parse::<GenericDialect>("CREATE TABLE mytable (struct INT);").unwrap();
+/// assert!(constrainer.validate_schema(&invalid_schema).is_err());
+///
+/// let valid_schema = ParserDB::parse::<GenericDialect>("CREATE TABLE mytable (class INT);").unwrap();
+/// assert!(constrainer.validate_schema(&valid_schema).is_ok());
+///
+/// // Schemas from which Python and TypeScript bindings are generated
+/// let constrainer: GenericConstrainer<ParserDB> = NoReservedIdentifier::new([
+///     ReservedWords::rust(RustEdition::Edition2024),
+///     ReservedWords::python(),
+///     ReservedWords::typescript(),
+/// ])
+/// .into();
+///
+/// let invalid_schema = ParserDB::parse::<GenericDialect>("CREATE TABLE mytable (class INT);").unwrap();
+/// assert!(constrainer.validate_schema(&invalid_schema).is_err());
+///
+/// let invalid_schema = ParserDB::parse::<GenericDialect>("CREATE TABLE gen (id INT);").unwrap();
+/// assert!(constrainer.validate_schema(&invalid_schema).is_err());
+///
+/// let valid_schema = ParserDB::parse::<GenericDialect>("CREATE TABLE mytable (class_name INT);").unwrap();
+/// assert!(constrainer.validate_schema(&valid_schema).is_ok());
+/// ```
+pub struct NoReservedIdentifier<DB> {
+    reserved_words: Vec<ReservedWords>,
+    _phantom: std::marker::PhantomData<DB>,
+}
+
+impl<DB> NoReservedIdentifier<DB> {
+    /// Creates a new `NoReservedIdentifier` rule with the provided sets of
+    /// reserved words.
+    #[must_use]
+    pub fn new(reserved_words: impl IntoIterator<Item = ReservedWords>) -> Self {
+        Self {
+            reserved_words: reserved_words.into_iter().collect(),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+
+    /// Adds the provided set of reserved words.
+    #[must_use]
+    pub fn with_reserved_words(mut self, reserved_words: ReservedWords) -> Self {
+        self.reserved_words.push(reserved_words);
+        self
+    }
+
+    /// Returns the names of the languages reserving the provided identifier.
+    fn reserving_languages(&self, identifier: &str) -> Vec<&str> {
+        self.reserved_words
+            .iter()
+            .filter(|reserved_words| reserved_words.contains(identifier))
+            .map(ReservedWords::language)
+            .collect()
+    }
+
+    /// Returns the error for an identifier of the provided kind which is
+    /// reserved in the provided languages, if any.
+    fn error(
+        &self,
+        kind: &str,
+        identifier: &str,
+        object: impl FnOnce() -> ObjectRef,
+    ) -> Option<RuleErrorInfo> {
+        let languages = self.reserving_languages(identifier);
+        if languages.is_empty() {
+            return None;
+        }
+        let languages = languages.join(", ");
+        let subject = kind.to_lowercase();
+        Some(
+            RuleErrorInfo::builder()
+                .rule("NoReservedIdentifier")
+                .unwrap()
+                .object(object())
+                .unwrap()
+                .message(format!(
+                    "{kind} name '{identifier}' is a reserved word in {languages}."
+                ))
+                .unwrap()
+                .resolution(format!(
+                    "Rename the {subject} '{identifier}' to something that is not reserved in {languages}."
+                ))
+                .unwrap()
+                .try_into()
+                .unwrap(),
+        )
+    }
+}
+
+impl<DB> Default for NoReservedIdentifier<DB> {
+    fn default() -> Self {
+        Self::new([ReservedWords::rust(RustEdition::default())])
+    }
+}
+
+impl<DB> Clone for NoReservedIdentifier<DB> {
+    fn clone(&self) -> Self {
+        Self::new(self.reserved_words.clone())
+    }
+}
+
+impl<DB: DatabaseLike + 'static> From<NoReservedIdentifier<DB>> for GenericConstrainer<DB> {
+    fn from(rule: NoReservedIdentifier<DB>) -> Self {
+        identifier_constrainer(rule)
+    }
+}
+
+impl<DB: DatabaseLike> TableRule for NoReservedIdentifier<DB> {
+    type Database = DB;
+
+    fn validate_table(
+        &self,
+        _database: &Self::Database,
+        table: &<Self::Database as DatabaseLike>::Table,
+    ) -> Result<(), crate::error::Error<DB>> {
+        if let Some(error) = self.error("Table", table.table_name(), || ObjectRef::table(table)) {
+            return Err(crate::error::Error::Table(
+                Box::new(table.clone()),
+                error.into(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl<DB: DatabaseLike> ColumnRule for NoReservedIdentifier<DB> {
+    type Database = DB;
+
+    fn validate_column(
+        &self,
+        database: &Self::Database,
+        column: &<Self::Database as DatabaseLike>::Column,
+    ) -> Result<(), crate::error::Error<DB>> {
+        if let Some(error) = self.error("Column", column.column_name(), || {
+            ObjectRef::column(database, column)
+        }) {
+            return Err(crate::error::Error::Column(
+                Box::new(column.clone()),
+                error.into(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl<DB: DatabaseLike> ForeignKeyRule for NoReservedIdentifier<DB> {
+    type Database = DB;
+
+    fn validate_foreign_key(
+        &self,
+        database: &Self::Database,
+        foreign_key: &<Self::Database as DatabaseLike>::ForeignKey,
+    ) -> Result<(), crate::error::Error<DB>> {
+        if let Some(name) = foreign_key.foreign_key_name()
+            && let Some(error) = self.error("Foreign key", name, || {
+                ObjectRef::foreign_key(database, foreign_key)
+            })
+        {
+            return Err(crate::error::Error::ForeignKey(
+                Box::new(foreign_key.clone()),
+                error.into(),
+            ));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,183 @@
+//! Submodule providing the `NoSqlReservedKeyword` rule, which enforces that
+//! identifiers are not keywords reserved by any of the configured SQL dialects.
+
+use sql_traits::traits::{ColumnLike, DatabaseLike, ForeignKeyLike};
+
+use crate::{
+    error::{ObjectRef, RuleErrorInfo},
+    rules::SqlDialect,
+    rules::named_objects::{identifier_constrainer, validate_table_objects},
+    traits::{ColumnRule, ForeignKeyRule, GenericConstrainer, TableRule},
+};
+
+/// Struct defining a rule that enforces that identifiers are not keywords
+/// reserved by any of the configured SQL dialects, as such identifiers must
+/// be quoted in every statement referring to them.
+///
+/// The rule covers the names of tables, columns, foreign keys, check
+/// constraints and indices. By default, only the PostgreSQL reserved keywords
+/// are considered.
+///
+/// # Example
+///
+/// ```rust
+/// use sql_rules::prelude::*;
+/// use sqlparser::dialect::GenericDialect;
+///
+/// let constrainer: GenericConstrainer<ParserDB> = NoSqlReservedKeyword::default().into();
+///
+/// let invalid_schema = ParserDB::parse::<GenericDialect>("CREATE TABLE \"user\" (id INT);").unwrap();
+/// assert!(constrainer.validate_schema(&invalid_schema).is_err());
+///
+/// let invalid_schema = ParserDB::parse::<GenericDialect>("CREATE TABLE items (\"order\" INT);").unwrap();
+/// assert!(constrainer.validate_schema(&invalid_schema).is_err());
+///
+/// let invalid_schema = ParserDB::parse::<GenericDialect>(
+///     "CREATE TABLE items (position INT, CONSTRAINT \"check\" CHECK (position > 0));",
+/// )
+/// .unwrap();
+/// assert!(constrainer.validate_schema(&invalid_schema).is_err());
+///
+/// let valid_schema = ParserDB::parse::<GenericDialect>("CREATE TABLE items (position INT);").unwrap();
+/// assert!(constrainer.validate_schema(&valid_schema).is_ok());
+///
+/// // `position` is reserved by the SQL standard
+/// let constrainer: GenericConstrainer<ParserDB> =
+///     NoSqlReservedKeyword::new([SqlDialect::PostgreSql, SqlDialect::Ansi]).into();
+/// assert!(constrainer.validate_schema(&valid_schema).is_err());
+/// ```
+pub struct NoSqlReservedKeyword<DB> {
+    dialects: Vec<SqlDialect>,
+    _phantom: std::marker::PhantomData<DB>,
+}
+
+impl<DB> NoSqlReservedKeyword<DB> {
+    /// Creates a new `NoSqlReservedKeyword` rule for the provided dialects.
+    pub fn new(dialects: impl IntoIterator<Item = SqlDialect>) -> Self {
+        Self {
+            dialects: dialects.into_iter().collect(),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+
+    /// Adds the provided dialect.
+    #[must_use]
+    pub fn with_dialect(mut self, dialect: SqlDialect) -> Self {
+        self.dialects.push(dialect);
+        self
+    }
+
+    /// Returns the error for an identifier of the provided kind which is a
+    /// reserved keyword in any of the configured dialects, if any.
+    fn error(
+        &self,
+        kind: &str,
+        identifier: &str,
+        object: impl FnOnce() -> ObjectRef,
+    ) -> Option<RuleErrorInfo> {
+        let dialects = self
+            .dialects
+            .iter()
+            .filter(|dialect| dialect.is_reserved_keyword(identifier))
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        if dialects.is_empty() {
+            return None;
+        }
+        let dialects = dialects.join(", ");
+        let subject = kind.to_lowercase();
+        Some(
+            RuleErrorInfo::builder()
+                .rule("NoSqlReservedKeyword")
+                .unwrap()
+                .object(object())
+                .unwrap()
+                .message(format!(
+                    "{kind} name '{identifier}' is a reserved keyword in {dialects}."
+                ))
+                .unwrap()
+                .resolution(format!(
+                    "Rename the {subject} '{identifier}' to something that is not reserved in {dialects}, so that it does not need to be quoted."
+                ))
+                .unwrap()
+                .try_into()
+                .unwrap(),
+        )
+    }
+}
+
+impl<DB> Default for NoSqlReservedKeyword<DB> {
+    fn default() -> Self {
+        Self::new([SqlDialect::default()])
+    }
+}
+
+impl<DB> Clone for NoSqlReservedKeyword<DB> {
+    fn clone(&self) -> Self {
+        Self::new(self.dialects.clone())
+    }
+}
+
+impl<DB: DatabaseLike + 'static> From<NoSqlReservedKeyword<DB>> for GenericConstrainer<DB> {
+    fn from(rule: NoSqlReservedKeyword<DB>) -> Self {
+        identifier_constrainer(rule)
+    }
+}
+
+impl<DB: DatabaseLike> TableRule for NoSqlReservedKeyword<DB> {
+    type Database = DB;
+
+    fn validate_table(
+        &self,
+        database: &Self::Database,
+        table: &<Self::Database as DatabaseLike>::Table,
+    ) -> Result<(), crate::error::Error<DB>> {
+        validate_table_objects(database, table, |object| {
+            self.error(object.kind(), object.name()?, || {
+                object.object_ref(database)
+            })
+        })
+    }
+}
+
+impl<DB: DatabaseLike> ColumnRule for NoSqlReservedKeyword<DB> {
+    type Database = DB;
+
+    fn validate_column(
+        &self,
+        database: &Self::Database,
+        column: &<Self::Database as DatabaseLike>::Column,
+    ) -> Result<(), crate::error::Error<DB>> {
+        if let Some(error) = self.error("Column", column.column_name(), || {
+            ObjectRef::column(database, column)
+        }) {
+            return Err(crate::error::Error::Column(
+                Box::new(column.clone()),
+                error.into(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl<DB: DatabaseLike> ForeignKeyRule for NoSqlReservedKeyword<DB> {
+    type Database = DB;
+
+    fn validate_foreign_key(
+        &self,
+        database: &Self::Database,
+        foreign_key: &<Self::Database as DatabaseLike>::ForeignKey,
+    ) -> Result<(), crate::error::Error<DB>> {
+        if let Some(name) = foreign_key.foreign_key_name()
+            && let Some(error) = self.error("Foreign key", name, || {
+                ObjectRef::foreign_key(database, foreign_key)
+            })
+        {
+            return Err(crate::error::Error::ForeignKey(
+                Box::new(foreign_key.clone()),
+                error.into(),
+            ));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,379 @@
+//! Submodule providing sets of reserved words of the target languages into
+//! which SQL schemas are commonly translated, such as Rust or Python bindings.
+
+use std::collections::BTreeSet;
+use std::fmt::Display;
+
+use crate::rules::rust_keywords::RUST_KEYWORDS;
+
+/// Rust keywords which were introduced by the 2018 edition.
+const RUST_2018_KEYWORDS: &[&str] = &["async", "await", "dyn", "try"];
+
+/// Rust keywords which were introduced by the 2024 edition.
+const RUST_2024_KEYWORDS: &[&str] = &["gen"];
+
+/// Python keywords.
+const PYTHON_KEYWORDS: &[&str] = &[
+    "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class", "continue",
+    "def", "del", "elif", "else", "except", "finally", "for", "from", "global", "if", "import",
+    "in", "is", "lambda", "nonlocal", "not", "or", "pass", "raise", "return", "try", "while",
+    "with", "yield",
+];
+
+/// TypeScript and JavaScript reserved words, including the ones reserved in
+/// strict mode.
+const TYPESCRIPT_KEYWORDS: &[&str] = &[
+    "await",
+    "break",
+    "case",
+    "catch",
+    "class",
+    "const",
+    "continue",
+    "debugger",
+    "default",
+    "delete",
+    "do",
+    "else",
+    "enum",
+    "export",
+    "extends",
+    "false",
+    "finally",
+    "for",
+    "function",
+    "if",
+    "implements",
+    "import",
+    "in",
+    "instanceof",
+    "interface",
+    "let",
+    "new",
+    "null",
+    "package",
+    "private",
+    "protected",
+    "public",
+    "return",
+    "static",
+    "super",
+    "switch",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "typeof",
+    "var",
+    "void",
+    "while",
+    "with",
+    "yield",
+];
+
+/// Go keywords.
+const GO_KEYWORDS: &[&str] = &[
+    "break",
+    "case",
+    "chan",
+    "const",
+    "continue",
+    "default",
+    "defer",
+    "else",
+    "fallthrough",
+    "for",
+    "func",
+    "go",
+    "goto",
+    "if",
+    "import",
+    "interface",
+    "map",
+    "package",
+    "range",
+    "return",
+    "select",
+    "struct",
+    "switch",
+    "type",
+    "var",
+];
+
+/// Java reserved keywords and literals.
+const JAVA_KEYWORDS: &[&str] = &[
+    "abstract",
+    "assert",
+    "boolean",
+    "break",
+    "byte",
+    "case",
+    "catch",
+    "char",
+    "class",
+    "const",
+    "continue",
+    "default",
+    "do",
+    "double",
+    "else",
+    "enum",
+    "extends",
+    "false",
+    "final",
+    "finally",
+    "float",
+    "for",
+    "goto",
+    "if",
+    "implements",
+    "import",
+    "instanceof",
+    "int",
+    "interface",
+    "long",
+    "native",
+    "new",
+    "null",
+    "package",
+    "private",
+    "protected",
+    "public",
+    "return",
+    "short",
+    "static",
+    "strictfp",
+    "super",
+    "switch",
+    "synchronized",
+    "this",
+    "throw",
+    "throws",
+    "transient",
+    "true",
+    "try",
+    "void",
+    "volatile",
+    "while",
+];
+
+/// C# reserved keywords.
+const CSHARP_KEYWORDS: &[&str] = &[
+    "abstract",
+    "as",
+    "base",
+    "bool",
+    "break",
+    "byte",
+    "case",
+    "catch",
+    "char",
+    "checked",
+    "class",
+    "const",
+    "continue",
+    "decimal",
+    "default",
+    "delegate",
+    "do",
+    "double",
+    "else",
+    "enum",
+    "event",
+    "explicit",
+    "extern",
+    "false",
+    "finally",
+    "fixed",
+    "float",
+    "for",
+    "foreach",
+    "goto",
+    "if",
+    "implicit",
+    "in",
+    "int",
+    "interface",
+    "internal",
+    "is",
+    "lock",
+    "long",
+    "namespace",
+    "new",
+    "null",
+    "object",
+    "operator",
+    "out",
+    "override",
+    "params",
+    "private",
+    "protected",
+    "public",
+    "readonly",
+    "ref",
+    "return",
+    "sbyte",
+    "sealed",
+    "short",
+    "sizeof",
+    "stackalloc",
+    "static",
+    "string",
+    "struct",
+    "switch",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "typeof",
+    "uint",
+    "ulong",
+    "unchecked",
+    "unsafe",
+    "ushort",
+    "using",
+    "virtual",
+    "void",
+    "volatile",
+    "while",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+/// Editions of the Rust language, which differ in their reserved keywords.
+pub enum RustEdition {
+    /// The 2015 edition.
+    Edition2015,
+    /// The 2018 edition, which reserves `async`, `await`, `dyn` and `try`.
+    Edition2018,
+    /// The 2021 edition, whose keywords match the 2018 edition.
+    #[default]
+    Edition2021,
+    /// The 2024 edition, which additionally reserves `gen`.
+    Edition2024,
+}
+
+impl Display for RustEdition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RustEdition::Edition2015 => write!(f, "2015"),
+            RustEdition::Edition2018 => write!(f, "2018"),
+            RustEdition::Edition2021 => write!(f, "2021"),
+            RustEdition::Edition2024 => write!(f, "2024"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// A named set of words reserved by a target language, which should be avoided
+/// as identifiers in SQL schemas from which bindings in that language are
+/// generated.
+///
+/// Words are matched case-sensitively, as the target languages are
+/// case-sensitive (e.g. `None` is reserved in Python while `none` is not).
+///
+/// # Example
+///
+/// ```rust
+/// use sql_rules::prelude::*;
+///
+/// let rust = ReservedWords::rust(RustEdition::Edition2024);
+/// assert!(rust.contains("gen"));
+/// assert!(!ReservedWords::rust(RustEdition::Edition2021).contains("gen"));
+/// assert!(ReservedWords::python().contains("def"));
+/// assert!(ReservedWords::typescript().contains("default"));
+///
+/// let custom = ReservedWords::new("MyDSL", ["node", "edge"]);
+/// assert_eq!(custom.language(), "MyDSL");
+/// assert!(custom.contains("edge"));
+/// ```
+pub struct ReservedWords {
+    /// Name of the language reserving the words.
+    language: String,
+    /// The reserved words.
+    words: BTreeSet<String>,
+}
+
+impl ReservedWords {
+    /// Creates a new set of reserved words for the provided language.
+    #[must_use]
+    pub fn new<S: Into<String>>(
+        language: impl Into<String>,
+        words: impl IntoIterator<Item = S>,
+    ) -> Self {
+        Self {
+            language: language.into(),
+            words: words.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Returns the Rust keywords of the provided edition.
+    #[must_use]
+    pub fn rust(edition: RustEdition) -> Self {
+        let words = RUST_KEYWORDS
+            .iter()
+            .filter(|word| {
+                edition != RustEdition::Edition2015 || !RUST_2018_KEYWORDS.contains(word)
+            })
+            .chain(
+                RUST_2024_KEYWORDS
+                    .iter()
+                    .filter(|_| edition == RustEdition::Edition2024),
+            )
+            .copied();
+        Self::new(format!("Rust {edition}"), words)
+    }
+
+    /// Returns the Python keywords.
+    #[must_use]
+    pub fn python() -> Self {
+        Self::new("Python", PYTHON_KEYWORDS.iter().copied())
+    }
+
+    /// Returns the TypeScript and JavaScript reserved words.
+    #[must_use]
+    pub fn typescript() -> Self {
+        Self::new("TypeScript", TYPESCRIPT_KEYWORDS.iter().copied())
+    }
+
+    /// Returns the Go keywords.
+    #[must_use]
+    pub fn go() -> Self {
+        Self::new("Go", GO_KEYWORDS.iter().copied())
+    }
+
+    /// Returns the Java reserved keywords and literals.
+    #[must_use]
+    pub fn java() -> Self {
+        Self::new("Java", JAVA_KEYWORDS.iter().copied())
+    }
+
+    /// Returns the C# reserved keywords.
+    #[must_use]
+    pub fn csharp() -> Self {
+        Self::new("C#", CSHARP_KEYWORDS.iter().copied())
+    }
+
+    /// Adds the provided word to the set.
+    #[must_use]
+    pub fn with_word(mut self, word: impl Into<String>) -> Self {
+        self.words.insert(word.into());
+        self
+    }
+
+    /// Returns the name of the language reserving the words.
+    #[must_use]
+    pub fn language(&self) -> &str {
+        &self.language
+    }
+
+    /// Returns whether the provided word is reserved.
+    #[must_use]
+    pub fn contains(&self, word: &str) -> bool {
+        self.words.contains(word)
+    }
+
+    /// Returns an iterator over the reserved words, in lexicographic order.
+    pub fn words(&self) -> impl Iterator<Item = &str> {
+        self.words.iter().map(String::as_str)
+    }
+}
@@ -1,9 +1,5 @@
 //! Submodule providing constraint structs that can be applied to tables.
 
-mod lowercase_table_name;
-pub use lowercase_table_name::LowercaseTableName;
-mod snake_case_table_name;
-pub use snake_case_table_name::SnakeCaseTableName;
 mod plural_table_name;
 pub use plural_table_name::PluralTableName;
 mod unique_check_rule;
@@ -16,8 +12,6 @@ mod has_primary_key;
 pub use has_primary_key::HasPrimaryKey;
 mod no_forbidden_column_in_extension;
 pub use no_forbidden_column_in_extension::NoForbiddenColumnInExtension;
-mod no_rust_keyword_table_name;
-pub use no_rust_keyword_table_name::NoRustKeywordTableName;
 mod no_negation_check_rule;
 pub use no_negation_check_rule::NoNegationCheckRule;
 mod non_redundant_extension_dag;
@@ -28,3 +22,21 @@ mod no_tautological_check_rule;
 pub use no_tautological_check_rule::NoTautologicalCheckRule;
 mod policies_require_row_level_security;
 pub use policies_require_row_level_security::PoliciesRequireRowLevelSecurity;
+mod no_foreign_key_cycle;
+pub use no_foreign_key_cycle::NoForeignKeyCycle;
+mod bounded_cascade_chain;
+pub use bounded_cascade_chain::BoundedCascadeChain;
+mod no_polymorphic_association;
+pub use no_polymorphic_association::NoPolymorphicAssociation;
+mod nulls_not_distinct_unique_index;
+pub use nulls_not_distinct_unique_index::NullsNotDistinctUniqueIndex;
+mod nullable_column_report;
+pub use nullable_column_report::NullableColumnReport;
+mod no_redundant_index;
+pub use no_redundant_index::NoRedundantIndex;
+mod no_subsumed_check_constraint;
+pub use no_subsumed_check_constraint::NoSubsumedCheckConstraint;
+mod no_unsatisfiable_check_constraint;
+pub use no_unsatisfiable_check_constraint::NoUnsatisfiableCheckConstraint;
+mod max_identifier_length;
+pub use max_identifier_length::MaxIdentifierLength;
@@ -0,0 +1,136 @@
+//! Submodule providing the `BoundedCascadeChain` constraint, which enforces
+//! that deleting a row cannot cascade into too many tables.
+
+use sql_traits::traits::{DatabaseLike, ForeignKeyLike, TableLike};
+
+use crate::{
+    error::{ObjectRef, RuleErrorInfo},
+    traits::{Constrainer, GenericConstrainer, TableRule},
+};
+
+/// Struct defining a constraint that enforces that deleting a row of a table
+/// cannot delete, through chains of `ON DELETE CASCADE` foreign keys, rows in
+/// more than a configurable number of tables (by default 5), the table itself
+/// included.
+///
+/// # Example
+///
+/// Here follows an example of validating an invalid SQL statement with the
+/// `BoundedCascadeChain` constraint.
+///
+/// ```rust
+/// use sql_rules::prelude::*;
+/// use sqlparser::dialect::GenericDialect;
+///
+/// let constrainer: GenericConstrainer<ParserDB> = BoundedCascadeChain::new(2).into();
+///
+/// let invalid_schema = ParserDB::parse::<GenericDialect>(
+///     "
+/// CREATE TABLE users (id INT PRIMARY KEY);
+/// CREATE TABLE posts (id INT PRIMARY KEY, user_id INT REFERENCES users (id) ON DELETE CASCADE);
+/// CREATE TABLE comments (id INT PRIMARY KEY, post_id INT REFERENCES posts (id) ON DELETE CASCADE);
+/// ",
+/// )
+/// .unwrap();
+/// assert!(constrainer.validate_schema(&invalid_schema).is_err());
+///
+/// let valid_schema = ParserDB::parse::<GenericDialect>(
+///     "
+/// CREATE TABLE users (id INT PRIMARY KEY);
+/// CREATE TABLE posts (id INT PRIMARY KEY, user_id INT REFERENCES users (id) ON DELETE CASCADE);
+/// CREATE TABLE comments (id INT PRIMARY KEY, post_id INT REFERENCES posts (id) ON DELETE RESTRICT);
+/// ",
+/// )
+/// .unwrap();
+/// assert!(constrainer.validate_schema(&valid_schema).is_ok());
+/// ```
+pub struct BoundedCascadeChain<DB> {
+    max_tables: usize,
+    _phantom: std::marker::PhantomData<DB>,
+}
+
+impl<DB> BoundedCascadeChain<DB> {
+    /// Creates a new `BoundedCascadeChain` constraint allowing deletes to
+    /// cascade into at most `max_tables` tables, the starting table included.
+    #[must_use]
+    pub fn new(max_tables: usize) -> Self {
+        Self {
+            max_tables,
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<DB> Default for BoundedCascadeChain<DB> {
+    fn default() -> Self {
+        Self::new(5)
+    }
+}
+
+impl<DB: DatabaseLike + 'static> From<BoundedCascadeChain<DB>> for GenericConstrainer<DB> {
+    fn from(constraint: BoundedCascadeChain<DB>) -> Self {
+        let mut constrainer = GenericConstrainer::default();
+        constrainer.register_table_rule(Box::new(constraint));
+        constrainer
+    }
+}
+
+impl<DB: DatabaseLike> TableRule for BoundedCascadeChain<DB> {
+    type Database = DB;
+
+    fn validate_table(
+        &self,
+        database: &Self::Database,
+        table: &<Self::Database as DatabaseLike>::Table,
+    ) -> Result<(), crate::error::Error<DB>> {
+        // Breadth-first visit of the tables whose rows are deleted in cascade.
+        let mut reached: Vec<&DB::Table> = vec![table];
+        let mut position = 0;
+        while let Some(&current) = reached.get(position) {
+            position += 1;
+            for candidate in database.tables() {
+                if reached.contains(&candidate) {
+                    continue;
+                }
+                if candidate.foreign_keys(database).any(|fk| {
+                    fk.on_delete_cascade(database) && fk.referenced_table(database) == current
+                }) {
+                    reached.push(candidate);
+                }
+            }
+        }
+
+        if reached.len() <= self.max_tables {
+            return Ok(());
+        }
+
+        let table_name = table.table_name();
+        let max_tables = self.max_tables;
+        let chain = reached
+            .iter()
+            .map(TableLike::table_name)
+            .collect::<Vec<_>>()
+            .join(", ");
+        let error: RuleErrorInfo = RuleErrorInfo::builder()
+            .rule("BoundedCascadeChain")
+            .unwrap()
+            .object(ObjectRef::table(table))
+            .unwrap()
+            .message(format!(
+                "Deleting a row of table '{table_name}' cascades into {} tables ({chain}), more than the allowed {max_tables}.",
+                reached.len()
+            ))
+            .unwrap()
+            .resolution(
+                "Replace some of the `ON DELETE CASCADE` actions along the chain with `ON DELETE RESTRICT`, so that large deletions must be performed explicitly."
+                    .to_string(),
+            )
+            .unwrap()
+            .try_into()
+            .unwrap();
+        Err(crate::error::Error::Table(
+            Box::new(table.clone()),
+            error.into(),
+        ))
+    }
+}
@@ -4,7 +4,7 @@
 use sql_traits::traits::{DatabaseLike, TableLike};
 
 use crate::{
-    error::RuleErrorInfo,
+    error::{ObjectRef, RuleErrorInfo},
     traits::{Constrainer, GenericConstrainer, TableRule},
 };
 
@@ -58,7 +58,7 @@ impl<DB: DatabaseLike> TableRule for HasPrimaryKey<DB> {
             let error: RuleErrorInfo = RuleErrorInfo::builder()
                 .rule("HasPrimaryKey")
                 .unwrap()
-                .object(table.table_name().to_owned())
+                .object(ObjectRef::table(table))
                 .unwrap()
                 .message(format!(
                     "Table '{}' does not have a primary key",
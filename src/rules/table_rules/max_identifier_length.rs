@@ -0,0 +1,297 @@
+//! Submodule providing the `MaxIdentifierLength` constraint, which enforces
+//! that identifiers do not exceed the maximum length of the configured SQL
+//! dialect.
+
+use sql_traits::traits::{
+    CheckConstraintLike, ColumnLike, DatabaseLike, ForeignKeyLike, IndexLike, PolicyLike, TableLike,
+};
+
+use crate::{
+    error::{ObjectRef, RuleErrorInfo},
+    rules::SqlDialect,
+    traits::{Constrainer, GenericConstrainer, TableRule},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// The namespaces within which identifiers must be unique.
+enum Namespace {
+    /// Tables and indices, unique within a schema.
+    Relation,
+    /// Columns, unique within a table.
+    Column,
+    /// Constraints, unique within a table.
+    Constraint,
+    /// Policies, unique within a table.
+    Policy,
+}
+
+/// An identifier of an object of a table.
+struct Identifier<'a> {
+    namespace: Namespace,
+    kind: &'static str,
+    name: &'a str,
+    object: ObjectRef,
+}
+
+/// Returns the names of the table and of its indices, which share the
+/// namespace of the relations of its schema.
+fn relation_names<'db, DB: DatabaseLike>(
+    database: &'db DB,
+    table: &'db DB::Table,
+) -> impl Iterator<Item = &'db str> {
+    std::iter::once(table.table_name())
+        .chain(table.indices(database).filter_map(IndexLike::index_name))
+        .chain(
+            table
+                .unique_indices(database)
+                .filter_map(IndexLike::index_name),
+        )
+}
+
+/// Returns the identifiers of the provided table and of its named objects.
+fn table_identifiers<'db, DB: DatabaseLike>(
+    database: &'db DB,
+    table: &'db DB::Table,
+) -> Vec<Identifier<'db>> {
+    let mut identifiers = vec![Identifier {
+        namespace: Namespace::Relation,
+        kind: "Table",
+        name: table.table_name(),
+        object: ObjectRef::table(table),
+    }];
+    identifiers.extend(table.columns(database).map(|column| Identifier {
+        namespace: Namespace::Column,
+        kind: "Column",
+        name: column.column_name(),
+        object: ObjectRef::column(database, column),
+    }));
+    identifiers.extend(table.foreign_keys(database).filter_map(|foreign_key| {
+        Some(Identifier {
+            namespace: Namespace::Constraint,
+            kind: "Foreign key",
+            name: foreign_key.foreign_key_name()?,
+            object: ObjectRef::foreign_key(database, foreign_key),
+        })
+    }));
+    identifiers.extend(table.check_constraints(database).filter_map(|cc| {
+        let name = cc.check_constraint_name()?;
+        Some(Identifier {
+            namespace: Namespace::Constraint,
+            kind: "Check constraint",
+            name,
+            object: ObjectRef::check_constraint(table, Some(name), cc.expression(database)),
+        })
+    }));
+    identifiers.extend(table.indices(database).filter_map(|index| {
+        let name = index.index_name()?;
+        Some(Identifier {
+            namespace: Namespace::Relation,
+            kind: "Index",
+            name,
+            object: ObjectRef::index(table, Some(name), index.expression(database)),
+        })
+    }));
+    identifiers.extend(table.unique_indices(database).filter_map(|index| {
+        let name = index.index_name()?;
+        Some(Identifier {
+            namespace: Namespace::Relation,
+            kind: "Unique constraint",
+            name,
+            object: ObjectRef::index(table, Some(name), index.expression(database)),
+        })
+    }));
+    identifiers.extend(table.policies(database).map(|policy| Identifier {
+        namespace: Namespace::Policy,
+        kind: "Policy",
+        name: policy.policy_name(),
+        object: ObjectRef::policy(table, policy.policy_name()),
+    }));
+    identifiers
+}
+
+/// Struct defining a constraint that enforces that the names of tables,
+/// columns, foreign keys, check constraints, indices and policies do not
+/// exceed the maximum identifier length of the configured SQL dialect, by
+/// default PostgreSQL.
+///
+/// PostgreSQL does not reject longer identifiers but silently truncates them
+/// to 63 bytes, so two long names sharing their first 63 bytes end up
+/// colliding: such collisions are reported as well, considering relations
+/// (tables and indices) across the schema and the columns, constraints and
+/// policies within each table.
+///
+/// # Example
+///
+/// Here follows an example of validating an invalid SQL statement with the
+/// `MaxIdentifierLength` constraint.
+///
+/// ```rust
+/// use sql_rules::prelude::*;
+/// use sqlparser::dialect::GenericDialect;
+///
+/// let constrainer: GenericConstrainer<ParserDB> = MaxIdentifierLength::default().into();
+///
+/// let long_name = "a".repeat(64);
+/// let invalid_schema = ParserDB::parse::<GenericDialect>(&format!(
+///     "CREATE TABLE items (id INT PRIMARY KEY, {long_name} INT);"
+/// ))
+/// .unwrap();
+/// assert!(constrainer.validate_schema(&invalid_schema).is_err());
+///
+/// let prefix = "b".repeat(63);
+/// let invalid_schema = ParserDB::parse::<GenericDialect>(&format!(
+///     "CREATE TABLE items (id INT PRIMARY KEY, qty INT, CONSTRAINT {prefix}_min CHECK (qty > 0), CONSTRAINT {prefix}_max CHECK (qty < 10));"
+/// ))
+/// .unwrap();
+/// assert!(constrainer.validate_schema(&invalid_schema).is_err());
+///
+/// let valid_schema = ParserDB::parse::<GenericDialect>(&format!(
+///     "CREATE TABLE items (id INT PRIMARY KEY, {} INT);",
+///     "a".repeat(63)
+/// ))
+/// .unwrap();
+/// assert!(constrainer.validate_schema(&valid_schema).is_ok());
+///
+/// // SQL Server accepts identifiers of up to 128 characters
+/// let constrainer: GenericConstrainer<ParserDB> =
+///     MaxIdentifierLength::new(SqlDialect::SqlServer).into();
+/// assert!(constrainer.validate_schema(&invalid_schema).is_ok());
+/// ```
+pub struct MaxIdentifierLength<DB> {
+    dialect: SqlDialect,
+    _phantom: std::marker::PhantomData<DB>,
+}
+
+impl<DB> MaxIdentifierLength<DB> {
+    /// Creates a new `MaxIdentifierLength` constraint for the provided dialect.
+    #[must_use]
+    pub fn new(dialect: SqlDialect) -> Self {
+        Self {
+            dialect,
+            _phantom: std::marker::PhantomData,
+        }
+    }
+
+    /// Returns the key identifying the provided name once folded to lowercase
+    /// and truncated by the dialect.
+    fn truncated_key(&self, name: &str) -> String {
+        self.dialect
+            .truncate_identifier(&name.to_lowercase())
+            .to_owned()
+    }
+}
+
+impl<DB> Default for MaxIdentifierLength<DB> {
+    fn default() -> Self {
+        Self::new(SqlDialect::default())
+    }
+}
+
+impl<DB: DatabaseLike + 'static> From<MaxIdentifierLength<DB>> for GenericConstrainer<DB> {
+    fn from(constraint: MaxIdentifierLength<DB>) -> Self {
+        let mut constrainer = GenericConstrainer::default();
+        constrainer.register_table_rule(Box::new(constraint));
+        constrainer
+    }
+}
+
+impl<DB: DatabaseLike> TableRule for MaxIdentifierLength<DB> {
+    type Database = DB;
+
+    fn validate_table(
+        &self,
+        database: &Self::Database,
+        table: &<Self::Database as DatabaseLike>::Table,
+    ) -> Result<(), crate::error::Error<DB>> {
+        let Some(max_length) = self.dialect.max_identifier_length() else {
+            return Ok(());
+        };
+
+        let identifiers = table_identifiers(database, table);
+
+        // Relations share their namespace with the ones of the other tables
+        // of the same schema, while the other objects are scoped to the table.
+        let relation_names = database
+            .tables()
+            .filter(|other| other.table_schema() == table.table_schema())
+            .flat_map(|other| relation_names(database, other))
+            .collect::<Vec<_>>();
+
+        let unit = if self.dialect.truncates_identifiers() {
+            "bytes"
+        } else {
+            "characters"
+        };
+        let dialect = self.dialect;
+        let table_name = table.table_name();
+        for identifier in &identifiers {
+            let length = dialect.identifier_length(identifier.name);
+            if length <= max_length {
+                continue;
+            }
+
+            let Identifier {
+                namespace,
+                kind,
+                name,
+                ..
+            } = identifier;
+            let subject = kind.to_lowercase();
+            let (message, resolution) = if dialect.truncates_identifiers() {
+                let truncated = dialect.truncate_identifier(name);
+                let key = self.truncated_key(name);
+                let candidates = match namespace {
+                    Namespace::Relation => relation_names.clone(),
+                    _ => identifiers
+                        .iter()
+                        .filter(|other| other.namespace == *namespace)
+                        .map(|other| other.name)
+                        .collect(),
+                };
+                let collision = candidates.into_iter().find(|other| {
+                    !other.eq_ignore_ascii_case(name) && self.truncated_key(other) == key
+                });
+                match collision {
+                    Some(other) => (
+                        format!(
+                            "{kind} name '{name}' on table '{table_name}' is {length} {unit} long and {dialect} truncates it to '{truncated}', colliding with '{other}'."
+                        ),
+                        format!(
+                            "Rename the {subject} '{name}' or '{other}' so that they differ within their first {max_length} {unit}."
+                        ),
+                    ),
+                    None => (
+                        format!(
+                            "{kind} name '{name}' on table '{table_name}' is {length} {unit} long and {dialect} silently truncates it to '{truncated}'."
+                        ),
+                        format!("Shorten the {subject} '{name}' to at most {max_length} {unit}."),
+                    ),
+                }
+            } else {
+                (
+                    format!(
+                        "{kind} name '{name}' on table '{table_name}' is {length} {unit} long, exceeding the maximum of {max_length} {unit} in {dialect}."
+                    ),
+                    format!("Shorten the {subject} '{name}' to at most {max_length} {unit}."),
+                )
+            };
+
+            let error: RuleErrorInfo = RuleErrorInfo::builder()
+                .rule("MaxIdentifierLength")
+                .unwrap()
+                .object(identifier.object.clone())
+                .unwrap()
+                .message(message)
+                .unwrap()
+                .resolution(resolution)
+                .unwrap()
+                .try_into()
+                .unwrap();
+            return Err(crate::error::Error::Table(
+                Box::new(table.clone()),
+                error.into(),
+            ));
+        }
+        Ok(())
+    }
+}
@@ -5,7 +5,7 @@
 use sql_traits::traits::{ColumnLike, DatabaseLike, TableLike};
 
 use crate::{
-    error::RuleErrorInfo,
+    error::{ObjectRef, RuleErrorInfo},
     traits::{Constrainer, GenericConstrainer, TableRule},
 };
 
@@ -144,7 +144,7 @@ impl<DB: DatabaseLike> TableRule for NoForbiddenColumnInExtension<DB> {
                 let error: RuleErrorInfo = RuleErrorInfo::builder()
                     .rule("NoForbiddenColumnInExtension")
                     .unwrap()
-                    .object(table_name.to_owned())
+                    .object(ObjectRef::column(database, column))
                     .unwrap()
                     .message(format!(
                         "Table '{}' extends {} ({}) but has a forbidden column named '{}'",
@@ -0,0 +1,253 @@
+//! Submodule providing the `NoForeignKeyCycle` constraint, which enforces that
+//! the foreign keys of the schema do not form cycles across tables.
+
+use sql_traits::traits::{ColumnLike, DatabaseLike, ForeignKeyLike, TableLike};
+
+use crate::{
+    error::{ObjectRef, RuleErrorInfo},
+    traits::{Constrainer, GenericConstrainer, TableRule},
+};
+
+/// Struct defining a constraint that enforces that the foreign keys of the
+/// schema do not form cycles, as rows of mutually-referencing tables cannot be
+/// inserted without deferring the constraints.
+///
+/// Each cycle is reported once, by the table with the smallest name among
+/// the ones in the cycle, as the ordered list of the tables and foreign keys
+/// forming it. When all the foreign keys of a cycle are `DEFERRABLE`, the
+/// error notes it.
+///
+/// Foreign keys whose host columns are all nullable do not take part in
+/// cycles, as rows may then be inserted with a `NULL` reference and updated
+/// afterwards: this applies to self-referencing foreign keys as well.
+///
+/// # Example
+///
+/// Here follows an example of validating an invalid SQL statement with the
+/// `NoForeignKeyCycle` constraint.
+///
+/// ```rust
+/// use sql_rules::prelude::*;
+/// use sqlparser::dialect::GenericDialect;
+///
+/// let constrainer: GenericConstrainer<ParserDB> = NoForeignKeyCycle::default().into();
+///
+/// let invalid_schema = ParserDB::parse::<GenericDialect>(
+///     "
+/// CREATE TABLE teams (
+///     id INT PRIMARY KEY,
+///     captain_id INT NOT NULL,
+///     CONSTRAINT teams_captain_fk FOREIGN KEY (captain_id) REFERENCES players (id)
+/// );
+/// CREATE TABLE players (id INT PRIMARY KEY, team_id INT NOT NULL REFERENCES teams (id));
+/// ",
+/// )
+/// .unwrap();
+/// assert!(constrainer.validate_schema(&invalid_schema).is_err());
+///
+/// // Self-references with a NOT NULL column cannot hold a first row
+/// let invalid_self_reference = ParserDB::parse::<GenericDialect>(
+///     "CREATE TABLE nodes (id INT PRIMARY KEY, parent_id INT NOT NULL REFERENCES nodes (id));",
+/// )
+/// .unwrap();
+/// assert!(constrainer.validate_schema(&invalid_self_reference).is_err());
+///
+/// // A nullable foreign key breaks the cycle
+/// let valid_cycle = ParserDB::parse::<GenericDialect>(
+///     "
+/// CREATE TABLE teams (
+///     id INT PRIMARY KEY,
+///     captain_id INT,
+///     CONSTRAINT teams_captain_fk FOREIGN KEY (captain_id) REFERENCES players (id)
+/// );
+/// CREATE TABLE players (id INT PRIMARY KEY, team_id INT NOT NULL REFERENCES teams (id));
+/// ",
+/// )
+/// .unwrap();
+/// assert!(constrainer.validate_schema(&valid_cycle).is_ok());
+///
+/// let valid_schema = ParserDB::parse::<GenericDialect>(
+///     "
+/// CREATE TABLE nodes (id INT PRIMARY KEY, parent_id INT REFERENCES nodes (id));
+/// CREATE TABLE edges (id INT PRIMARY KEY, source_id INT NOT NULL REFERENCES nodes (id));
+/// ",
+/// )
+/// .unwrap();
+/// assert!(constrainer.validate_schema(&valid_schema).is_ok());
+/// ```
+pub struct NoForeignKeyCycle<DB>(std::marker::PhantomData<DB>);
+
+impl<DB> Default for NoForeignKeyCycle<DB> {
+    fn default() -> Self {
+        Self(std::marker::PhantomData)
+    }
+}
+
+impl<DB: DatabaseLike + 'static> From<NoForeignKeyCycle<DB>> for GenericConstrainer<DB> {
+    fn from(constraint: NoForeignKeyCycle<DB>) -> Self {
+        let mut constrainer = GenericConstrainer::default();
+        constrainer.register_table_rule(Box::new(constraint));
+        constrainer
+    }
+}
+
+/// Returns the key used to order tables, so that each cycle is reported
+/// only by its smallest table.
+fn table_key<T: TableLike>(table: &T) -> (Option<&str>, &str) {
+    (table.table_schema(), table.table_name())
+}
+
+impl<DB: DatabaseLike> NoForeignKeyCycle<DB> {
+    /// Extends the provided path of foreign keys, which starts from `start`
+    /// and currently ends in `current`, collecting the cycles back to `start`
+    /// which only visit tables greater than `start`. Foreign keys whose host
+    /// columns are all nullable are not followed, as they cannot block the
+    /// insertion of rows.
+    fn collect_cycles<'db>(
+        database: &'db DB,
+        start: &'db DB::Table,
+        current: &'db DB::Table,
+        path: &mut Vec<&'db DB::ForeignKey>,
+        cycles: &mut Vec<Vec<&'db DB::ForeignKey>>,
+    ) {
+        for foreign_key in current.foreign_keys(database) {
+            let next = foreign_key.referenced_table(database);
+            if next == current || Self::is_nullable(database, foreign_key) {
+                continue;
+            }
+            path.push(foreign_key);
+            if next == start {
+                cycles.push(path.clone());
+            } else if table_key(next) > table_key(start)
+                && !path[..path.len() - 1]
+                    .iter()
+                    .any(|fk| fk.host_table(database) == next)
+            {
+                Self::collect_cycles(database, start, next, path, cycles);
+            }
+            path.pop();
+        }
+    }
+
+    /// Returns whether all the host columns of the provided foreign key are
+    /// nullable.
+    fn is_nullable(database: &DB, foreign_key: &DB::ForeignKey) -> bool {
+        foreign_key
+            .host_columns(database)
+            .all(|column| column.is_nullable(database))
+    }
+
+    /// Returns a human-readable label for the provided foreign key.
+    fn foreign_key_label(database: &DB, foreign_key: &DB::ForeignKey) -> String {
+        foreign_key.foreign_key_name().map_or_else(
+            || {
+                format!(
+                    "({})",
+                    foreign_key
+                        .host_columns(database)
+                        .map(ColumnLike::column_name)
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )
+            },
+            ToString::to_string,
+        )
+    }
+
+    /// Returns a human-readable description of the provided cycle.
+    fn describe_cycle(database: &DB, cycle: &[&DB::ForeignKey]) -> String {
+        let mut description = cycle
+            .first()
+            .map(|fk| fk.host_table(database).table_name().to_string())
+            .unwrap_or_default();
+        description.extend(cycle.iter().map(|foreign_key| {
+            format!(
+                " -[{}]-> {}",
+                Self::foreign_key_label(database, foreign_key),
+                foreign_key.referenced_table(database).table_name()
+            )
+        }));
+        if cycle.iter().all(|fk| fk.is_deferrable(database)) {
+            description.push_str(" (all constraints are DEFERRABLE)");
+        }
+        description
+    }
+}
+
+impl<DB: DatabaseLike> TableRule for NoForeignKeyCycle<DB> {
+    type Database = DB;
+
+    fn validate_table(
+        &self,
+        database: &Self::Database,
+        table: &<Self::Database as DatabaseLike>::Table,
+    ) -> Result<(), crate::error::Error<DB>> {
+        let table_name = table.table_name();
+
+        for foreign_key in table.foreign_keys(database) {
+            if foreign_key.referenced_table(database) != table
+                || Self::is_nullable(database, foreign_key)
+            {
+                continue;
+            }
+            let label = Self::foreign_key_label(database, foreign_key);
+            let deferrable_note = if foreign_key.is_deferrable(database) {
+                " The constraint is DEFERRABLE, so rows may still be inserted within a transaction."
+            } else {
+                ""
+            };
+            let error: RuleErrorInfo = RuleErrorInfo::builder()
+                .rule("NoForeignKeyCycle")
+                .unwrap()
+                .object(ObjectRef::foreign_key(database, foreign_key))
+                .unwrap()
+                .message(format!(
+                    "Table '{table_name}' references itself via foreign key {label} with non-nullable host columns, so its first row cannot be inserted.{deferrable_note}"
+                ))
+                .unwrap()
+                .resolution(format!(
+                    "Make the host columns of foreign key {label} nullable, so that root rows may hold a NULL reference."
+                ))
+                .unwrap()
+                .try_into()
+                .unwrap();
+            return Err(crate::error::Error::Table(
+                Box::new(table.clone()),
+                error.into(),
+            ));
+        }
+
+        let mut cycles = Vec::new();
+        Self::collect_cycles(database, table, table, &mut Vec::new(), &mut cycles);
+        if cycles.is_empty() {
+            return Ok(());
+        }
+
+        let descriptions = cycles
+            .iter()
+            .map(|cycle| Self::describe_cycle(database, cycle))
+            .collect::<Vec<_>>()
+            .join("; ");
+        let error: RuleErrorInfo = RuleErrorInfo::builder()
+            .rule("NoForeignKeyCycle")
+            .unwrap()
+            .object(ObjectRef::table(table))
+            .unwrap()
+            .message(format!(
+                "Table '{table_name}' is part of {} foreign key cycle(s): {descriptions}",
+                cycles.len()
+            ))
+            .unwrap()
+            .resolution(
+                "Break each cycle by removing one of its foreign keys, by moving the reference into a separate linking table, or by making the host columns of one of its foreign keys nullable."
+                    .to_string(),
+            )
+            .unwrap()
+            .try_into()
+            .unwrap();
+        Err(crate::error::Error::Table(
+            Box::new(table.clone()),
+            error.into(),
+        ))
+    }
+}
@@ -5,7 +5,7 @@
 use sql_traits::traits::{CheckConstraintLike, DatabaseLike, TableLike};
 
 use crate::{
-    error::RuleErrorInfo,
+    error::{ObjectRef, RuleErrorInfo},
     traits::{Constrainer, GenericConstrainer, TableRule},
 };
 
@@ -77,25 +77,22 @@ impl<DB: DatabaseLike> TableRule for NoNegationCheckRule<DB> {
         database: &Self::Database,
         table: &<Self::Database as DatabaseLike>::Table,
     ) -> Result<(), crate::error::Error<DB>> {
-        if table
+        // Find the first negation check constraint
+        if let Some(cc) = table
             .check_constraints(database)
-            .any(|cc| cc.is_negation(database))
+            .find(|cc| cc.is_negation(database))
         {
             let table_name = table.table_name();
-
-            // Find the first negation check constraint
-            let negation_constraint = table
-                .check_constraints(database)
-                .find(|cc| cc.is_negation(database))
-                .map_or_else(
-                    || "unknown".to_string(),
-                    |cc| cc.expression(database).to_string(),
-                );
+            let negation_constraint = cc.expression(database).to_string();
 
             let error: RuleErrorInfo = RuleErrorInfo::builder()
                 .rule("NoNegationCheckRule")
                 .unwrap()
-                .object(table_name.to_owned())
+                .object(ObjectRef::check_constraint(
+                    table,
+                    cc.check_constraint_name(),
+                    &negation_constraint,
+                ))
                 .unwrap()
                 .message(format!(
                     "Table '{table_name}' has a negation check constraint: CHECK ({negation_constraint})"
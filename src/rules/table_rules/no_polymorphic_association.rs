@@ -0,0 +1,176 @@
+//! Submodule providing the `NoPolymorphicAssociation` constraint, which
+//! enforces that tables do not reference rows of several tables through a
+//! discriminator column and an identifier without a foreign key.
+
+use sql_traits::traits::{ColumnLike, DatabaseLike, ForeignKeyLike, TableLike};
+
+use crate::{
+    error::{ObjectRef, RuleErrorInfo},
+    rules::InflectionDictionary,
+    traits::{Constrainer, GenericConstrainer, TableRule},
+};
+
+/// Struct defining a constraint that detects the polymorphic association
+/// anti-pattern: a pair of columns such as `<x>_type` and `<x>_id` (or
+/// `<x>_kind` and `<x>_id`), where the discriminator column tells which table
+/// the identifier column refers to, and the identifier column is therefore
+/// not the host column of any foreign key.
+///
+/// Such references cannot be enforced by the database. The recommended
+/// alternative is to have the candidate tables extend a common parent table,
+/// and to reference the parent table with a regular foreign key, whose name
+/// the error suggests by pluralizing the shared prefix with an
+/// [`InflectionDictionary`].
+///
+/// # Example
+///
+/// Here follows an example of validating an invalid SQL statement with the
+/// `NoPolymorphicAssociation` constraint.
+///
+/// ```rust
+/// use sql_rules::prelude::*;
+/// use sqlparser::dialect::GenericDialect;
+///
+/// let constrainer: GenericConstrainer<ParserDB> = NoPolymorphicAssociation::default().into();
+///
+/// let invalid_schema = ParserDB::parse::<GenericDialect>(
+///     "CREATE TABLE comments (id INT PRIMARY KEY, target_type TEXT, target_id INT);",
+/// )
+/// .unwrap();
+/// assert!(constrainer.validate_schema(&invalid_schema).is_err());
+///
+/// let valid_schema = ParserDB::parse::<GenericDialect>(
+///     "
+/// CREATE TABLE commentables (id INT PRIMARY KEY);
+/// CREATE TABLE posts (id INT PRIMARY KEY REFERENCES commentables (id) ON DELETE CASCADE);
+/// CREATE TABLE photos (id INT PRIMARY KEY REFERENCES commentables (id) ON DELETE CASCADE);
+/// CREATE TABLE comments (id INT PRIMARY KEY, commentable_id INT REFERENCES commentables (id));
+/// ",
+/// )
+/// .unwrap();
+/// assert!(constrainer.validate_schema(&valid_schema).is_ok());
+///
+/// // Scientific plurals are suggested through the dictionary
+/// let invalid_schema = ParserDB::parse::<GenericDialect>(
+///     "CREATE TABLE annotations (id INT PRIMARY KEY, taxon_type TEXT, taxon_id INT);",
+/// )
+/// .unwrap();
+/// let error = constrainer.validate_schema(&invalid_schema).unwrap_err();
+/// assert!(error.to_string().contains("'taxa'"));
+///
+/// let invalid_schema = ParserDB::parse::<GenericDialect>(
+///     "CREATE TABLE peaks (id INT PRIMARY KEY, spectrum_kind TEXT, spectrum_id INT);",
+/// )
+/// .unwrap();
+/// let error = constrainer.validate_schema(&invalid_schema).unwrap_err();
+/// assert!(error.to_string().contains("'spectra'"));
+/// ```
+pub struct NoPolymorphicAssociation<DB> {
+    discriminator_suffixes: Vec<String>,
+    id_suffix: String,
+    dictionary: InflectionDictionary,
+    _phantom: std::marker::PhantomData<DB>,
+}
+
+impl<DB> NoPolymorphicAssociation<DB> {
+    /// Adds a suffix identifying discriminator columns, by default `_type`
+    /// and `_kind`.
+    #[must_use]
+    pub fn with_discriminator_suffix(mut self, suffix: impl Into<String>) -> Self {
+        self.discriminator_suffixes.push(suffix.into());
+        self
+    }
+
+    /// Sets the suffix identifying identifier columns, by default `_id`.
+    #[must_use]
+    pub fn with_id_suffix(mut self, suffix: impl Into<String>) -> Self {
+        self.id_suffix = suffix.into();
+        self
+    }
+
+    /// Sets the dictionary of the exceptions to the inflection rules.
+    #[must_use]
+    pub fn with_dictionary(mut self, dictionary: InflectionDictionary) -> Self {
+        self.dictionary = dictionary;
+        self
+    }
+}
+
+impl<DB> Default for NoPolymorphicAssociation<DB> {
+    fn default() -> Self {
+        Self {
+            discriminator_suffixes: vec!["_type".to_string(), "_kind".to_string()],
+            id_suffix: "_id".to_string(),
+            dictionary: InflectionDictionary::default(),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<DB: DatabaseLike + 'static> From<NoPolymorphicAssociation<DB>> for GenericConstrainer<DB> {
+    fn from(constraint: NoPolymorphicAssociation<DB>) -> Self {
+        let mut constrainer = GenericConstrainer::default();
+        constrainer.register_table_rule(Box::new(constraint));
+        constrainer
+    }
+}
+
+impl<DB: DatabaseLike> TableRule for NoPolymorphicAssociation<DB> {
+    type Database = DB;
+
+    fn validate_table(
+        &self,
+        database: &Self::Database,
+        table: &<Self::Database as DatabaseLike>::Table,
+    ) -> Result<(), crate::error::Error<DB>> {
+        for discriminator in table.columns(database) {
+            let discriminator_name = discriminator.column_name();
+            let Some(prefix) = self
+                .discriminator_suffixes
+                .iter()
+                .find_map(|suffix| discriminator_name.strip_suffix(suffix.as_str()))
+            else {
+                continue;
+            };
+            if prefix.is_empty() {
+                continue;
+            }
+            let id_name = format!("{prefix}{}", self.id_suffix);
+            let Some(id_column) = table
+                .columns(database)
+                .find(|column| column.column_name() == id_name)
+            else {
+                continue;
+            };
+            if table.foreign_keys(database).any(|fk| {
+                fk.host_columns(database)
+                    .any(|host_column| host_column == id_column)
+            }) {
+                continue;
+            }
+
+            let table_name = table.table_name();
+            let parent_table_name = self.dictionary.pluralize_name(prefix);
+            let error: RuleErrorInfo = RuleErrorInfo::builder()
+                .rule("NoPolymorphicAssociation")
+                .unwrap()
+                .object(ObjectRef::column(database, id_column))
+                .unwrap()
+                .message(format!(
+                    "Table '{table_name}' has a polymorphic association: column '{discriminator_name}' tells which table column '{id_name}' refers to, so the reference cannot be enforced by a foreign key."
+                ))
+                .unwrap()
+                .resolution(format!(
+                    "Create a parent table (e.g. '{parent_table_name}') which each candidate table extends through a foreign key on its primary key, making them extension tables, then replace '{discriminator_name}' and '{id_name}' with a foreign key referencing the parent table."
+                ))
+                .unwrap()
+                .try_into()
+                .unwrap();
+            return Err(crate::error::Error::Table(
+                Box::new(table.clone()),
+                error.into(),
+            ));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,276 @@
+//! Submodule providing the `NoRedundantIndex` constraint, which enforces that
+//! tables do not have indices made redundant by another index or by the
+//! primary key.
+
+use sql_traits::traits::{ColumnLike, DatabaseLike, IndexLike, TableLike};
+use sqlparser::ast::Expr;
+
+use crate::{
+    error::{ObjectRef, RuleErrorInfo},
+    rules::{
+        expression_analysis::{index_elements, normalized_key},
+        name_pattern::matches_glob,
+    },
+    traits::{Constrainer, GenericConstrainer, TableRule},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+/// Kinds of indices, from the weakest to the strongest.
+enum IndexKind {
+    /// A non-unique index.
+    NonUnique,
+    /// A unique index or constraint.
+    Unique,
+    /// The primary key.
+    PrimaryKey,
+}
+
+/// An index of a table, with its elements normalized for comparison.
+struct IndexEntry<'db> {
+    kind: IndexKind,
+    name: Option<&'db str>,
+    expression: Option<&'db Expr>,
+    elements: Vec<String>,
+}
+
+impl IndexEntry<'_> {
+    /// Returns a human-readable description of the index.
+    fn describe(&self) -> String {
+        let elements = self.elements.join(", ");
+        match (self.kind, self.name) {
+            (IndexKind::PrimaryKey, _) => format!("the primary key ({elements})"),
+            (IndexKind::Unique, Some(name)) => format!("unique index '{name}' ({elements})"),
+            (IndexKind::Unique, None) => format!("unique index ({elements})"),
+            (IndexKind::NonUnique, Some(name)) => format!("index '{name}' ({elements})"),
+            (IndexKind::NonUnique, None) => format!("index ({elements})"),
+        }
+    }
+}
+
+/// Struct defining a constraint that enforces that no index of a table is
+/// made redundant by another index or by the primary key, which is the case
+/// when:
+///
+/// 1. A unique index has the same elements as the primary key.
+/// 2. A non-unique index has the same elements as a unique index or as the
+///    primary key.
+/// 3. Two indices of the same kind have the same elements.
+/// 4. The elements of a non-unique index are a leading prefix of the elements
+///    of another index or of the primary key.
+///
+/// Elements are compared regardless of their formatting and of the case of
+/// unquoted identifiers, so that `(a, b)`, `(A,b)` and `("a", "b")` are
+/// equivalent, while the quoted `("A", b)` is not. The error names the index
+/// to drop.
+///
+/// Partial indices (`WHERE ...`) and indices using another access method than
+/// a B-tree (e.g. `USING gin`) only serve a subset of the rows or other
+/// operators, and should not be compared with plain indices. The indices
+/// exposed by `sql-traits` carry neither their predicate nor their access
+/// method, so they cannot be recognized and skipped by default yet: until
+/// they can, ignore them by name or by a glob pattern matching a naming
+/// convention such as `*_partial_idx`, in which case they are neither
+/// reported nor considered to cover other indices.
+///
+/// # Example
+///
+/// Here follows an example of validating an invalid SQL statement with the
+/// `NoRedundantIndex` constraint.
+///
+/// ```rust
+/// use sql_rules::prelude::*;
+/// use sqlparser::dialect::GenericDialect;
+///
+/// let constrainer: GenericConstrainer<ParserDB> = NoRedundantIndex::default().into();
+///
+/// // Invalid: the first index is a leading prefix of the second one
+/// let invalid_schema = ParserDB::parse::<GenericDialect>(
+///     "
+/// CREATE TABLE posts (id INT PRIMARY KEY, user_id INT, created_at INT);
+/// CREATE INDEX posts_user_id_idx ON posts (user_id);
+/// CREATE INDEX posts_user_id_created_at_idx ON posts (user_id, created_at);
+/// ",
+/// )
+/// .unwrap();
+/// assert!(constrainer.validate_schema(&invalid_schema).is_err());
+///
+/// // Invalid: the unique constraint duplicates the primary key
+/// let invalid_schema =
+///     ParserDB::parse::<GenericDialect>("CREATE TABLE users (id INT PRIMARY KEY, UNIQUE (id));")
+///         .unwrap();
+/// assert!(constrainer.validate_schema(&invalid_schema).is_err());
+///
+/// let valid_schema = ParserDB::parse::<GenericDialect>(
+///     "
+/// CREATE TABLE posts (id INT PRIMARY KEY, user_id INT, created_at INT);
+/// CREATE INDEX posts_user_id_created_at_idx ON posts (user_id, created_at);
+/// CREATE INDEX posts_created_at_idx ON posts (created_at);
+/// ",
+/// )
+/// .unwrap();
+/// assert!(constrainer.validate_schema(&valid_schema).is_ok());
+///
+/// // Partial indices must be ignored, as their predicate is not compared
+/// let partial_schema = ParserDB::parse::<GenericDialect>(
+///     "
+/// CREATE TABLE posts (id INT PRIMARY KEY, user_id INT, created_at INT, deleted_at INT);
+/// CREATE INDEX posts_active_user_id_idx ON posts (user_id) WHERE deleted_at IS NULL;
+/// CREATE INDEX posts_user_id_created_at_idx ON posts (user_id, created_at);
+/// ",
+/// )
+/// .unwrap();
+/// let constrainer: GenericConstrainer<ParserDB> = NoRedundantIndex::default()
+///     .with_ignored_index("posts_active_user_id_idx")
+///     .into();
+/// assert!(constrainer.validate_schema(&partial_schema).is_ok());
+///
+/// // Or ignored by a naming convention
+/// let constrainer: GenericConstrainer<ParserDB> = NoRedundantIndex::default()
+///     .with_ignored_index("*_active_*")
+///     .into();
+/// assert!(constrainer.validate_schema(&partial_schema).is_ok());
+/// ```
+pub struct NoRedundantIndex<DB> {
+    ignored_indices: Vec<String>,
+    _phantom: std::marker::PhantomData<DB>,
+}
+
+impl<DB> NoRedundantIndex<DB> {
+    /// Excludes the indices whose name matches the provided name or glob
+    /// pattern (where `*` matches any sequence of characters) from the
+    /// comparison, as needed for partial indices and for indices using
+    /// another access method.
+    #[must_use]
+    pub fn with_ignored_index(mut self, index_name: impl Into<String>) -> Self {
+        self.ignored_indices.push(index_name.into());
+        self
+    }
+
+    /// Returns whether the index with the provided name is ignored.
+    fn is_ignored(&self, index_name: Option<&str>) -> bool {
+        index_name.is_some_and(|index_name| {
+            self.ignored_indices
+                .iter()
+                .any(|ignored| matches_glob(ignored, index_name))
+        })
+    }
+}
+
+impl<DB> Default for NoRedundantIndex<DB> {
+    fn default() -> Self {
+        Self {
+            ignored_indices: Vec::new(),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<DB: DatabaseLike + 'static> From<NoRedundantIndex<DB>> for GenericConstrainer<DB> {
+    fn from(constraint: NoRedundantIndex<DB>) -> Self {
+        let mut constrainer = GenericConstrainer::default();
+        constrainer.register_table_rule(Box::new(constraint));
+        constrainer
+    }
+}
+
+impl<DB: DatabaseLike> TableRule for NoRedundantIndex<DB> {
+    type Database = DB;
+
+    fn validate_table(
+        &self,
+        database: &Self::Database,
+        table: &<Self::Database as DatabaseLike>::Table,
+    ) -> Result<(), crate::error::Error<DB>> {
+        let mut entries = Vec::new();
+        let primary_key = table
+            .primary_key_columns(database)
+            .map(|column| column.column_name().to_lowercase())
+            .collect::<Vec<_>>();
+        if !primary_key.is_empty() {
+            entries.push(IndexEntry {
+                kind: IndexKind::PrimaryKey,
+                name: None,
+                expression: None,
+                elements: primary_key,
+            });
+        }
+        entries.extend(
+            table
+                .unique_indices(database)
+                .filter(|index| !self.is_ignored(index.index_name()))
+                .map(|index| IndexEntry {
+                    kind: IndexKind::Unique,
+                    name: index.index_name(),
+                    expression: Some(index.expression(database)),
+                    elements: index_elements(index.expression(database))
+                        .into_iter()
+                        .map(normalized_key)
+                        .collect(),
+                }),
+        );
+        entries.extend(
+            table
+                .indices(database)
+                .filter(|index| !self.is_ignored(index.index_name()))
+                .map(|index| IndexEntry {
+                    kind: IndexKind::NonUnique,
+                    name: index.index_name(),
+                    expression: Some(index.expression(database)),
+                    elements: index_elements(index.expression(database))
+                        .into_iter()
+                        .map(normalized_key)
+                        .collect(),
+                }),
+        );
+
+        for (position, candidate) in entries.iter().enumerate() {
+            let Some(expression) = candidate.expression else {
+                continue;
+            };
+            let covering = entries
+                .iter()
+                .enumerate()
+                .find_map(|(other_position, other)| {
+                    let duplicates = other_position != position
+                        && other.elements == candidate.elements
+                        && (other.kind > candidate.kind
+                            || (other.kind == candidate.kind && other_position < position));
+                    let extends = candidate.kind == IndexKind::NonUnique
+                        && other.elements.len() > candidate.elements.len()
+                        && other.elements.starts_with(&candidate.elements);
+                    if duplicates {
+                        Some((other, "duplicates"))
+                    } else if extends {
+                        Some((other, "is a leading prefix of"))
+                    } else {
+                        None
+                    }
+                });
+            let Some((other, relation)) = covering else {
+                continue;
+            };
+
+            let table_name = table.table_name();
+            let candidate_description = candidate.describe();
+            let error: RuleErrorInfo = RuleErrorInfo::builder()
+                .rule("NoRedundantIndex")
+                .unwrap()
+                .object(ObjectRef::index(table, candidate.name, expression))
+                .unwrap()
+                .message(format!(
+                    "On table '{table_name}', {candidate_description} {relation} {}, which already serves its lookups.",
+                    other.describe()
+                ))
+                .unwrap()
+                .resolution(format!("Drop {candidate_description}."))
+                .unwrap()
+                .try_into()
+                .unwrap();
+            return Err(crate::error::Error::Table(
+                Box::new(table.clone()),
+                error.into(),
+            ));
+        }
+        Ok(())
+    }
+}
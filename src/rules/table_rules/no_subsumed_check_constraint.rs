@@ -0,0 +1,152 @@
+//! Submodule providing the `NoSubsumedCheckConstraint` constraint, which
+//! enforces that no check constraint of a table is implied by another one.
+
+use sql_traits::traits::{CheckConstraintLike, DatabaseLike, TableLike};
+
+use crate::{
+    error::{ObjectRef, RuleErrorInfo},
+    rules::expression_analysis::{canonical_form, constraint_bounds},
+    traits::{Constrainer, GenericConstrainer, TableRule},
+};
+
+/// Struct defining a constraint that enforces that no check constraint of a
+/// table is implied by another check constraint of the same table, such as
+/// `CHECK (length(name) <= 255)` next to `CHECK (length(name) <= 100)`, or
+/// `CHECK (qty >= 0)` next to `CHECK (qty > 0)`.
+///
+/// The analysis covers the check constraints made of comparisons between a
+/// column (or an expression such as `length(name)`) and numeric literals,
+/// possibly combined with `AND` or written with `BETWEEN`. The weaker,
+/// redundant constraint is reported for removal.
+///
+/// # Example
+///
+/// Here follows an example of validating an invalid SQL statement with the
+/// `NoSubsumedCheckConstraint` constraint.
+///
+/// ```rust
+/// use sql_rules::prelude::*;
+/// use sqlparser::dialect::GenericDialect;
+///
+/// let constrainer: GenericConstrainer<ParserDB> = NoSubsumedCheckConstraint::default().into();
+///
+/// let invalid_schema = ParserDB::parse::<GenericDialect>(
+///     "CREATE TABLE items (id INT PRIMARY KEY, name TEXT, CHECK (length(name) <= 255), CHECK (length(name) <= 100));",
+/// )
+/// .unwrap();
+/// assert!(constrainer.validate_schema(&invalid_schema).is_err());
+///
+/// let invalid_schema = ParserDB::parse::<GenericDialect>(
+///     "CREATE TABLE items (id INT PRIMARY KEY, qty INT, CHECK (qty > 0), CHECK (qty >= 0));",
+/// )
+/// .unwrap();
+/// assert!(constrainer.validate_schema(&invalid_schema).is_err());
+///
+/// let valid_schema = ParserDB::parse::<GenericDialect>(
+///     "CREATE TABLE items (id INT PRIMARY KEY, qty INT, CHECK (qty > 0), CHECK (qty < 100));",
+/// )
+/// .unwrap();
+/// assert!(constrainer.validate_schema(&valid_schema).is_ok());
+/// ```
+pub struct NoSubsumedCheckConstraint<DB>(std::marker::PhantomData<DB>);
+
+impl<DB> Default for NoSubsumedCheckConstraint<DB> {
+    fn default() -> Self {
+        Self(std::marker::PhantomData)
+    }
+}
+
+impl<DB: DatabaseLike + 'static> From<NoSubsumedCheckConstraint<DB>> for GenericConstrainer<DB> {
+    fn from(constraint: NoSubsumedCheckConstraint<DB>) -> Self {
+        let mut constrainer = GenericConstrainer::default();
+        constrainer.register_table_rule(Box::new(constraint));
+        constrainer
+    }
+}
+
+impl<DB: DatabaseLike> TableRule for NoSubsumedCheckConstraint<DB> {
+    type Database = DB;
+
+    fn validate_table(
+        &self,
+        database: &Self::Database,
+        table: &<Self::Database as DatabaseLike>::Table,
+    ) -> Result<(), crate::error::Error<DB>> {
+        let constraints = table
+            .check_constraints(database)
+            .map(|constraint| {
+                let expression = constraint.expression(database);
+                (
+                    constraint,
+                    canonical_form(expression),
+                    constraint_bounds(expression),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        // Whether the bounds of the first constraint are implied by the bounds
+        // of the second one.
+        let is_implied_by =
+            |weaker: usize, stronger: usize| {
+                let (_, _, weaker_bounds) = &constraints[weaker];
+                let (_, _, stronger_bounds) = &constraints[stronger];
+                weaker_bounds.is_exact
+                    && !weaker_bounds.intervals.is_empty()
+                    && weaker_bounds
+                        .intervals
+                        .iter()
+                        .all(|(subject, weaker_interval)| {
+                            stronger_bounds.intervals.get(subject).is_some_and(
+                                |stronger_interval| weaker_interval.contains(stronger_interval),
+                            )
+                        })
+            };
+
+        for (position, (constraint, canonical, _)) in constraints.iter().enumerate() {
+            // Equivalent constraints are reported by `UniqueCheckRule`, and of
+            // two constraints implying each other only the latter is reported.
+            let Some((stronger, _, _)) = constraints
+                .iter()
+                .enumerate()
+                .find(|&(other_position, (_, other_canonical, _))| {
+                    let is_mutual = is_implied_by(other_position, position);
+                    other_position != position
+                        && other_canonical != canonical
+                        && is_implied_by(position, other_position)
+                        && (other_position < position || !is_mutual)
+                })
+                .map(|(_, other)| other)
+            else {
+                continue;
+            };
+
+            let table_name = table.table_name();
+            let weaker_description = ObjectRef::describe_check_constraint(database, *constraint);
+            let stronger_description = ObjectRef::describe_check_constraint(database, *stronger);
+            let error: RuleErrorInfo = RuleErrorInfo::builder()
+                .rule("NoSubsumedCheckConstraint")
+                .unwrap()
+                .object(ObjectRef::check_constraint(
+                    table,
+                    constraint.check_constraint_name(),
+                    constraint.expression(database),
+                ))
+                .unwrap()
+                .message(format!(
+                    "Check constraint {weaker_description} on table '{table_name}' is implied by check constraint {stronger_description}, so it never rejects a row on its own."
+                ))
+                .unwrap()
+                .resolution(format!(
+                    "Drop the weaker check constraint {weaker_description}."
+                ))
+                .unwrap()
+                .try_into()
+                .unwrap();
+            return Err(crate::error::Error::Table(
+                Box::new(table.clone()),
+                error.into(),
+            ));
+        }
+        Ok(())
+    }
+}
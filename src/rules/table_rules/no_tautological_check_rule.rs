@@ -5,7 +5,7 @@
 use sql_traits::traits::{CheckConstraintLike, DatabaseLike, TableLike};
 
 use crate::{
-    error::RuleErrorInfo,
+    error::{ObjectRef, RuleErrorInfo},
     traits::{Constrainer, GenericConstrainer, TableRule},
 };
 
@@ -81,20 +81,16 @@ impl<DB: DatabaseLike> TableRule for NoTautologicalCheckRule<DB> {
         for check_constraint in table.check_constraints(database) {
             if check_constraint.is_tautology(database) {
                 let table_name = table.table_name();
-
-                // Find the first tautological check constraint
-                let tautological_constraint = table
-                    .check_constraints(database)
-                    .find(|cc| cc.is_tautology(database))
-                    .map_or_else(
-                        || "unknown".to_string(),
-                        |cc| cc.expression(database).to_string(),
-                    );
+                let tautological_constraint = check_constraint.expression(database).to_string();
 
                 let error: RuleErrorInfo = RuleErrorInfo::builder()
                     .rule("NoTautologicalCheckRule")
                     .unwrap()
-                    .object(table_name.to_owned())
+                    .object(ObjectRef::check_constraint(
+                        table,
+                        check_constraint.check_constraint_name(),
+                        &tautological_constraint,
+                    ))
                     .unwrap()
                     .message(format!(
                         "Table '{table_name}' has a tautological check constraint: CHECK ({tautological_constraint})"
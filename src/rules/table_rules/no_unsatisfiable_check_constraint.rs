@@ -0,0 +1,193 @@
+//! Submodule providing the `NoUnsatisfiableCheckConstraint` constraint, which
+//! enforces that the check constraints of a table do not contradict each
+//! other.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use sql_traits::traits::{CheckConstraintLike, DatabaseLike, TableLike};
+
+use crate::{
+    error::{ObjectRef, RuleErrorInfo},
+    rules::expression_analysis::{Interval, constraint_bounds},
+    traits::{Constrainer, GenericConstrainer, TableRule},
+};
+
+#[derive(Default)]
+/// The values of a subject allowed by the check constraints seen so far.
+struct SubjectDomain {
+    /// The interval the subject must belong to.
+    interval: Interval,
+    /// The values the subject must be one of, if constrained by `IN` lists.
+    values: Option<BTreeSet<String>>,
+    /// The positions of the check constraints constraining the subject.
+    constraints: Vec<usize>,
+}
+
+impl SubjectDomain {
+    /// Returns whether no value of the subject is allowed.
+    fn is_empty(&self) -> bool {
+        self.interval.is_empty()
+            || self.values.as_ref().is_some_and(|values| {
+                values.iter().all(|value| {
+                    value
+                        .parse::<f64>()
+                        .is_ok_and(|value| !self.interval.contains_value(value))
+                })
+            })
+    }
+}
+
+/// Struct defining a constraint that enforces that the check constraints of a
+/// table can hold together, by reasoning on the intervals and sets of values
+/// they allow for each column (or expression such as `length(name)`).
+///
+/// It detects combinations such as `a > 10` with `a < 5`, `length(x) <= 0`
+/// with a not-empty check on `x`, or an `IN` list disjoint from another `IN`
+/// list (or from the interval allowed by comparisons) on the same column,
+/// whether within a single check constraint or across several of them. Such
+/// a table cannot accept any row where the constrained column is not `NULL`.
+///
+/// # Example
+///
+/// Here follows an example of validating an invalid SQL statement with the
+/// `NoUnsatisfiableCheckConstraint` constraint.
+///
+/// ```rust
+/// use sql_rules::prelude::*;
+/// use sqlparser::dialect::GenericDialect;
+///
+/// let constrainer: GenericConstrainer<ParserDB> = NoUnsatisfiableCheckConstraint::default().into();
+///
+/// let invalid_schema = ParserDB::parse::<GenericDialect>(
+///     "CREATE TABLE items (id INT PRIMARY KEY, qty INT, CHECK (qty > 10), CHECK (qty < 5));",
+/// )
+/// .unwrap();
+/// assert!(constrainer.validate_schema(&invalid_schema).is_err());
+///
+/// let invalid_schema = ParserDB::parse::<GenericDialect>(
+///     "CREATE TABLE items (id INT PRIMARY KEY, name TEXT, CHECK (name <> ''), CHECK (length(name) <= 0));",
+/// )
+/// .unwrap();
+/// assert!(constrainer.validate_schema(&invalid_schema).is_err());
+///
+/// let invalid_schema = ParserDB::parse::<GenericDialect>(
+///     "CREATE TABLE items (id INT PRIMARY KEY, status TEXT, CHECK (status IN ('new', 'sold')), CHECK (status IN ('lost')));",
+/// )
+/// .unwrap();
+/// assert!(constrainer.validate_schema(&invalid_schema).is_err());
+///
+/// let valid_schema = ParserDB::parse::<GenericDialect>(
+///     "CREATE TABLE items (id INT PRIMARY KEY, qty INT, status TEXT, CHECK (qty > 0), CHECK (qty < 5), CHECK (status IN ('new', 'sold')), CHECK (status IN ('sold', 'lost')));",
+/// )
+/// .unwrap();
+/// assert!(constrainer.validate_schema(&valid_schema).is_ok());
+///
+/// // Numeric values are compared by value rather than by spelling.
+/// let invalid_schema = ParserDB::parse::<GenericDialect>(
+///     "CREATE TABLE items (id INT PRIMARY KEY, delta INT, CHECK (delta IN (-1, -2)), CHECK (delta > 0));",
+/// )
+/// .unwrap();
+/// assert!(constrainer.validate_schema(&invalid_schema).is_err());
+///
+/// let valid_schema = ParserDB::parse::<GenericDialect>(
+///     "CREATE TABLE items (id INT PRIMARY KEY, qty INT, CHECK (qty IN (1, 2)), CHECK (qty IN (1.0, 2.0)));",
+/// )
+/// .unwrap();
+/// assert!(constrainer.validate_schema(&valid_schema).is_ok());
+/// ```
+pub struct NoUnsatisfiableCheckConstraint<DB>(std::marker::PhantomData<DB>);
+
+impl<DB> Default for NoUnsatisfiableCheckConstraint<DB> {
+    fn default() -> Self {
+        Self(std::marker::PhantomData)
+    }
+}
+
+impl<DB: DatabaseLike + 'static> From<NoUnsatisfiableCheckConstraint<DB>>
+    for GenericConstrainer<DB>
+{
+    fn from(constraint: NoUnsatisfiableCheckConstraint<DB>) -> Self {
+        let mut constrainer = GenericConstrainer::default();
+        constrainer.register_table_rule(Box::new(constraint));
+        constrainer
+    }
+}
+
+impl<DB: DatabaseLike> TableRule for NoUnsatisfiableCheckConstraint<DB> {
+    type Database = DB;
+
+    fn validate_table(
+        &self,
+        database: &Self::Database,
+        table: &<Self::Database as DatabaseLike>::Table,
+    ) -> Result<(), crate::error::Error<DB>> {
+        let constraints = table.check_constraints(database).collect::<Vec<_>>();
+        let mut domains: BTreeMap<String, SubjectDomain> = BTreeMap::new();
+
+        for (position, constraint) in constraints.iter().enumerate() {
+            let bounds = constraint_bounds(constraint.expression(database));
+            let subjects = bounds
+                .intervals
+                .keys()
+                .chain(bounds.value_sets.keys())
+                .collect::<BTreeSet<_>>();
+            for subject in subjects {
+                let domain = domains.entry(subject.clone()).or_default();
+                domain.constraints.push(position);
+                if let Some(interval) = bounds.intervals.get(subject) {
+                    domain.interval = domain.interval.intersection(*interval);
+                }
+                if let Some(values) = bounds.value_sets.get(subject) {
+                    domain.values = Some(match domain.values.take() {
+                        Some(existing) => existing.intersection(values).cloned().collect(),
+                        None => values.clone(),
+                    });
+                }
+                if !domain.is_empty() {
+                    continue;
+                }
+
+                let table_name = table.table_name();
+                let descriptions = domain
+                    .constraints
+                    .iter()
+                    .map(|&position| {
+                        ObjectRef::describe_check_constraint(database, constraints[position])
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let message = if domain.constraints.len() == 1 {
+                    format!(
+                        "Check constraint {descriptions} on table '{table_name}' can never hold for a non-NULL value of `{subject}`."
+                    )
+                } else {
+                    format!(
+                        "Check constraints {descriptions} on table '{table_name}' can never hold together for a non-NULL value of `{subject}`."
+                    )
+                };
+                let error: RuleErrorInfo = RuleErrorInfo::builder()
+                    .rule("NoUnsatisfiableCheckConstraint")
+                    .unwrap()
+                    .object(ObjectRef::check_constraint(
+                        table,
+                        constraint.check_constraint_name(),
+                        constraint.expression(database),
+                    ))
+                    .unwrap()
+                    .message(message)
+                    .unwrap()
+                    .resolution(format!(
+                        "Fix the bounds of `{subject}` in {descriptions}, so that at least one value satisfies them."
+                    ))
+                    .unwrap()
+                    .try_into()
+                    .unwrap();
+                return Err(crate::error::Error::Table(
+                    Box::new(table.clone()),
+                    error.into(),
+                ));
+            }
+        }
+        Ok(())
+    }
+}
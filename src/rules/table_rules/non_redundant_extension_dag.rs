@@ -10,7 +10,7 @@
 use sql_traits::traits::{DatabaseLike, TableLike};
 
 use crate::{
-    error::RuleErrorInfo,
+    error::{ObjectRef, RuleErrorInfo},
     traits::{Constrainer, GenericConstrainer, TableRule},
 };
 
@@ -116,7 +116,7 @@ impl<DB: DatabaseLike> TableRule for NonRedundantExtensionDag<DB> {
                     let error: RuleErrorInfo = RuleErrorInfo::builder()
                         .rule("NonRedundantExtensionDag")
                         .unwrap()
-                        .object(table.table_name().to_owned())
+                        .object(ObjectRef::table(table))
                         .unwrap()
                         .message(format!(
                             "Table '{}' has redundant extension structure (duplicate or transitive extensions)",
@@ -0,0 +1,144 @@
+//! Submodule providing the `NullableColumnReport` constraint, which lists the
+//! nullable columns of tables for review.
+
+use sql_traits::traits::{ColumnLike, DatabaseLike, TableLike};
+
+use crate::{
+    error::{ObjectRef, RuleErrorInfo},
+    traits::{Constrainer, GenericConstrainer, TableRule},
+};
+
+/// Struct defining a constraint that reports the tables with more nullable
+/// columns than allowed (by default none), listing them for review, since
+/// columns are nullable unless declared otherwise and nullability is often
+/// left by omission rather than by design.
+///
+/// Columns which were reviewed and are nullable by design can be allowed.
+///
+/// # Example
+///
+/// Here follows an example of validating an invalid SQL statement with the
+/// `NullableColumnReport` constraint.
+///
+/// ```rust
+/// use sql_rules::prelude::*;
+/// use sqlparser::dialect::GenericDialect;
+///
+/// let constrainer: GenericConstrainer<ParserDB> = NullableColumnReport::default().into();
+///
+/// let schema = ParserDB::parse::<GenericDialect>(
+///     "CREATE TABLE users (id INT PRIMARY KEY, name TEXT NOT NULL, nickname TEXT, bio TEXT);",
+/// )
+/// .unwrap();
+/// assert!(constrainer.validate_schema(&schema).is_err());
+///
+/// // Reviewed columns can be allowed
+/// let constrainer: GenericConstrainer<ParserDB> = NullableColumnReport::default()
+///     .with_allowed_column("nickname")
+///     .with_allowed_column("users.bio")
+///     .into();
+/// assert!(constrainer.validate_schema(&schema).is_ok());
+///
+/// // A number of nullable columns per table can be tolerated
+/// let constrainer: GenericConstrainer<ParserDB> =
+///     NullableColumnReport::default().with_max_nullable_columns(2).into();
+/// assert!(constrainer.validate_schema(&schema).is_ok());
+/// ```
+pub struct NullableColumnReport<DB> {
+    max_nullable_columns: usize,
+    allowed_columns: Vec<String>,
+    _phantom: std::marker::PhantomData<DB>,
+}
+
+impl<DB> NullableColumnReport<DB> {
+    /// Sets the number of nullable columns tolerated per table, by default 0.
+    #[must_use]
+    pub fn with_max_nullable_columns(mut self, max_nullable_columns: usize) -> Self {
+        self.max_nullable_columns = max_nullable_columns;
+        self
+    }
+
+    /// Adds a column allowed to be nullable, either as `column` (in any
+    /// table) or as `table.column`.
+    #[must_use]
+    pub fn with_allowed_column(mut self, column: impl Into<String>) -> Self {
+        self.allowed_columns.push(column.into());
+        self
+    }
+
+    /// Returns whether the provided column is allowed to be nullable.
+    fn is_allowed(&self, table_name: &str, column_name: &str) -> bool {
+        self.allowed_columns
+            .iter()
+            .any(|allowed| match allowed.split_once('.') {
+                Some((allowed_table, allowed_column)) => {
+                    allowed_table.eq_ignore_ascii_case(table_name)
+                        && allowed_column.eq_ignore_ascii_case(column_name)
+                }
+                None => allowed.eq_ignore_ascii_case(column_name),
+            })
+    }
+}
+
+impl<DB> Default for NullableColumnReport<DB> {
+    fn default() -> Self {
+        Self {
+            max_nullable_columns: 0,
+            allowed_columns: Vec::new(),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<DB: DatabaseLike + 'static> From<NullableColumnReport<DB>> for GenericConstrainer<DB> {
+    fn from(constraint: NullableColumnReport<DB>) -> Self {
+        let mut constrainer = GenericConstrainer::default();
+        constrainer.register_table_rule(Box::new(constraint));
+        constrainer
+    }
+}
+
+impl<DB: DatabaseLike> TableRule for NullableColumnReport<DB> {
+    type Database = DB;
+
+    fn validate_table(
+        &self,
+        database: &Self::Database,
+        table: &<Self::Database as DatabaseLike>::Table,
+    ) -> Result<(), crate::error::Error<DB>> {
+        let table_name = table.table_name();
+        let nullable_columns = table
+            .columns(database)
+            .filter(|column| column.is_nullable(database))
+            .map(ColumnLike::column_name)
+            .filter(|column_name| !self.is_allowed(table_name, column_name))
+            .collect::<Vec<_>>();
+        if nullable_columns.len() <= self.max_nullable_columns {
+            return Ok(());
+        }
+
+        let max_nullable_columns = self.max_nullable_columns;
+        let listed_columns = nullable_columns.join(", ");
+        let error: RuleErrorInfo = RuleErrorInfo::builder()
+            .rule("NullableColumnReport")
+            .unwrap()
+            .object(ObjectRef::table(table))
+            .unwrap()
+            .message(format!(
+                "Table '{table_name}' has {} nullable columns to review ({listed_columns}), more than the tolerated {max_nullable_columns}.",
+                nullable_columns.len()
+            ))
+            .unwrap()
+            .resolution(
+                "Declare the columns which must always have a value as NOT NULL, and allow the columns which are nullable by design."
+                    .to_string(),
+            )
+            .unwrap()
+            .try_into()
+            .unwrap();
+        Err(crate::error::Error::Table(
+            Box::new(table.clone()),
+            error.into(),
+        ))
+    }
+}
@@ -0,0 +1,102 @@
+//! Submodule providing the `NullsNotDistinctUniqueIndex` constraint, which
+//! enforces that unique indices on nullable columns treat `NULL` values as
+//! equal.
+
+use sql_traits::traits::{ColumnLike, DatabaseLike, IndexLike, TableLike};
+
+use crate::{
+    error::{ObjectRef, RuleErrorInfo},
+    traits::{Constrainer, GenericConstrainer, TableRule},
+};
+
+/// Struct defining a constraint that enforces that unique indices including
+/// nullable columns are declared with `NULLS NOT DISTINCT`, since by default
+/// `NULL` values are distinct from each other, and rows which differ only by
+/// having `NULL` in those columns are therefore not considered duplicates.
+///
+/// # Example
+///
+/// Here follows an example of validating an invalid SQL statement with the
+/// `NullsNotDistinctUniqueIndex` constraint.
+///
+/// ```rust
+/// use sql_rules::prelude::*;
+/// use sqlparser::dialect::GenericDialect;
+///
+/// let constrainer: GenericConstrainer<ParserDB> = NullsNotDistinctUniqueIndex::default().into();
+///
+/// let invalid_schema = ParserDB::parse::<GenericDialect>(
+///     "CREATE TABLE users (id INT PRIMARY KEY, team_id INT, name TEXT NOT NULL, UNIQUE (team_id, name));",
+/// )
+/// .unwrap();
+/// assert!(constrainer.validate_schema(&invalid_schema).is_err());
+///
+/// let valid_schema = ParserDB::parse::<GenericDialect>(
+///     "CREATE TABLE users (id INT PRIMARY KEY, team_id INT NOT NULL, name TEXT NOT NULL, UNIQUE (team_id, name));",
+/// )
+/// .unwrap();
+/// assert!(constrainer.validate_schema(&valid_schema).is_ok());
+/// ```
+pub struct NullsNotDistinctUniqueIndex<DB>(std::marker::PhantomData<DB>);
+
+impl<DB> Default for NullsNotDistinctUniqueIndex<DB> {
+    fn default() -> Self {
+        Self(std::marker::PhantomData)
+    }
+}
+
+impl<DB: DatabaseLike + 'static> From<NullsNotDistinctUniqueIndex<DB>> for GenericConstrainer<DB> {
+    fn from(constraint: NullsNotDistinctUniqueIndex<DB>) -> Self {
+        let mut constrainer = GenericConstrainer::default();
+        constrainer.register_table_rule(Box::new(constraint));
+        constrainer
+    }
+}
+
+impl<DB: DatabaseLike> TableRule for NullsNotDistinctUniqueIndex<DB> {
+    type Database = DB;
+
+    fn validate_table(
+        &self,
+        database: &Self::Database,
+        table: &<Self::Database as DatabaseLike>::Table,
+    ) -> Result<(), crate::error::Error<DB>> {
+        for index in table.unique_indices(database) {
+            if index.is_nulls_not_distinct(database) {
+                continue;
+            }
+            let nullable_columns = index
+                .columns(database)
+                .filter(|column| column.is_nullable(database))
+                .map(ColumnLike::column_name)
+                .collect::<Vec<_>>();
+            if nullable_columns.is_empty() {
+                continue;
+            }
+
+            let table_name = table.table_name();
+            let expression = index.expression(database);
+            let nullable_columns = nullable_columns.join(", ");
+            let error: RuleErrorInfo = RuleErrorInfo::builder()
+                .rule("NullsNotDistinctUniqueIndex")
+                .unwrap()
+                .object(ObjectRef::index(table, index.index_name(), expression))
+                .unwrap()
+                .message(format!(
+                    "Unique index on '{table_name}' ({expression}) includes the nullable columns {nullable_columns}, so rows differing only by NULL values in them are not considered duplicates."
+                ))
+                .unwrap()
+                .resolution(format!(
+                    "Declare the unique index with `NULLS NOT DISTINCT`, or make the columns {nullable_columns} NOT NULL."
+                ))
+                .unwrap()
+                .try_into()
+                .unwrap();
+            return Err(crate::error::Error::Table(
+                Box::new(table.clone()),
+                error.into(),
+            ));
+        }
+        Ok(())
+    }
+}
@@ -1,11 +1,11 @@
 //! Submodule providing the `PluralTableName` constraint, which enforces that
 //! the last segment of table names is plural.
 
-use inflection_rs::inflection::pluralize;
 use sql_traits::traits::{DatabaseLike, TableLike};
 
 use crate::{
-    error::RuleErrorInfo,
+    error::{ObjectRef, RuleErrorInfo},
+    rules::InflectionDictionary,
     traits::{Constrainer, GenericConstrainer, TableRule},
 };
 
@@ -13,7 +13,11 @@ use crate::{
 /// names is plural.
 ///
 /// For table names with underscores (e.g., `user_accounts`), only the last
-/// segment after the final underscore is checked for plurality.
+/// segment after the final underscore is checked for plurality, unless the
+/// name ends with one of the compound suffixes of the [`InflectionDictionary`].
+/// The dictionary also provides the uncountable and irregular words, by
+/// default a scientific vocabulary, so that tables such as `sample_metadata`
+/// are not flagged.
 ///
 /// # Example
 ///
@@ -58,12 +62,41 @@ use crate::{
 ///
 /// let invalid_taxon = ParserDB::parse::<GenericDialect>("CREATE TABLE taxon (id INT);").unwrap();
 /// assert!(constrainer.validate_schema(&invalid_taxon).is_err());
+///
+/// // Uncountable scientific words
+/// let valid_metadata = ParserDB::parse::<GenericDialect>("CREATE TABLE sample_metadata (id INT);").unwrap();
+/// assert!(constrainer.validate_schema(&valid_metadata).is_ok());
+///
+/// let valid_spectrometry = ParserDB::parse::<GenericDialect>("CREATE TABLE mass_spectrometry (id INT);").unwrap();
+/// assert!(constrainer.validate_schema(&valid_spectrometry).is_ok());
+///
+/// // Custom dictionary
+/// let constrainer: GenericConstrainer<ParserDB> = PluralTableName::default()
+///     .with_dictionary(InflectionDictionary::default().with_uncountable("sheep"))
+///     .into();
+/// let valid_sheep = ParserDB::parse::<GenericDialect>("CREATE TABLE farm_sheep (id INT);").unwrap();
+/// assert!(constrainer.validate_schema(&valid_sheep).is_ok());
 /// ```
-pub struct PluralTableName<DB>(std::marker::PhantomData<DB>);
+pub struct PluralTableName<DB> {
+    dictionary: InflectionDictionary,
+    _phantom: std::marker::PhantomData<DB>,
+}
+
+impl<DB> PluralTableName<DB> {
+    /// Sets the dictionary of the exceptions to the inflection rules.
+    #[must_use]
+    pub fn with_dictionary(mut self, dictionary: InflectionDictionary) -> Self {
+        self.dictionary = dictionary;
+        self
+    }
+}
 
 impl<DB> Default for PluralTableName<DB> {
     fn default() -> Self {
-        Self(std::marker::PhantomData)
+        Self {
+            dictionary: InflectionDictionary::default(),
+            _phantom: std::marker::PhantomData,
+        }
     }
 }
 
@@ -84,27 +117,23 @@ impl<DB: DatabaseLike> TableRule for PluralTableName<DB> {
         table: &<Self::Database as DatabaseLike>::Table,
     ) -> Result<(), crate::error::Error<DB>> {
         let table_name = table.table_name();
-        let last_segment = table_name.split('_').next_back().unwrap_or(table_name);
+        let last_segment = self.dictionary.last_segment(table_name);
 
         // Check if the last segment is plural by verifying that pluralizing it doesn't
         // change it
-        let pluralized = pluralize(last_segment);
+        let pluralized = self.dictionary.pluralize(last_segment);
 
         if pluralized == last_segment {
             Ok(())
         } else {
             let expected_plural = pluralized;
-            let expected_name = if table_name.contains('_') {
-                let prefix = &table_name[..table_name.rfind('_').unwrap()];
-                format!("{}_{}", prefix, &expected_plural)
-            } else {
-                expected_plural.clone()
-            };
+            let prefix = &table_name[..table_name.len() - last_segment.len()];
+            let expected_name = format!("{prefix}{expected_plural}");
 
             let error: RuleErrorInfo = RuleErrorInfo::builder()
                 .rule("PluralTableName")
                 .unwrap()
-                .object(table_name.to_owned())
+                .object(ObjectRef::table(table))
                 .unwrap()
                 .message(format!(
                     "Table '{table_name}' violates plural naming convention: the last segment '{last_segment}' is singular, not plural"
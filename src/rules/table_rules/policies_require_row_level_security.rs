@@ -3,7 +3,7 @@
 use sql_traits::traits::{DatabaseLike, TableLike};
 
 use crate::{
-    error::RuleErrorInfo,
+    error::{ObjectRef, RuleErrorInfo},
     traits::{Constrainer, GenericConstrainer, TableRule},
 };
 
@@ -68,7 +68,7 @@ impl<DB: DatabaseLike> TableRule for PoliciesRequireRowLevelSecurity<DB> {
             let error: RuleErrorInfo = RuleErrorInfo::builder()
                 .rule("PoliciesRequireRowLevelSecurity")
                 .unwrap()
-                .object(table.table_name().to_owned())
+                .object(ObjectRef::table(table))
                 .unwrap()
                 .message(format!(
                     "Table '{}' has policies but RLS is not enabled",
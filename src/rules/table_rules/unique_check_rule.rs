@@ -5,11 +5,18 @@
 use sql_traits::traits::{CheckConstraintLike, DatabaseLike, TableLike};
 
 use crate::{
-    error::RuleErrorInfo,
+    error::{ObjectRef, RuleErrorInfo},
+    rules::expression_analysis::canonical_form,
     traits::{Constrainer, GenericConstrainer, TableRule},
 };
 
-/// Struct defining a constraint that enforces that table names are lowercase.
+/// Struct defining a constraint that enforces that no two check constraints
+/// of a table are semantically equivalent.
+///
+/// Clauses are compared through a canonical form of their AST, so that
+/// `CHECK (a > 0)` and `CHECK (0 < a)`, `CHECK (a + b > 0)` and
+/// `CHECK (b + a > 0)`, or `CHECK (length(x) <= 10)` and
+/// `CHECK (char_length(x) <= 10)` are duplicates.
 ///
 /// # Example
 ///
@@ -27,6 +34,18 @@ use crate::{
 ///         .unwrap();
 /// assert!(constrainer.validate_schema(&invalid_schema).is_err());
 ///
+/// let invalid_schema = ParserDB::parse::<GenericDialect>(
+///     "CREATE TABLE mytable (id INT, name TEXT, CONSTRAINT positive_id CHECK (id > 0), CONSTRAINT id_above_zero CHECK (0 < id));",
+/// )
+/// .unwrap();
+/// assert!(constrainer.validate_schema(&invalid_schema).is_err());
+///
+/// let invalid_schema = ParserDB::parse::<GenericDialect>(
+///     "CREATE TABLE mytable (id INT, name TEXT, CHECK (length(name) <= 10), CHECK (char_length(name) <= 10));",
+/// )
+/// .unwrap();
+/// assert!(constrainer.validate_schema(&invalid_schema).is_err());
+///
 /// let valid_schema =
 ///     ParserDB::parse::<GenericDialect>("CREATE TABLE mytable (id INT, CHECK (id > 0));").unwrap();
 /// assert!(constrainer.validate_schema(&valid_schema).is_ok());
@@ -55,29 +74,43 @@ impl<DB: DatabaseLike> TableRule for UniqueCheckRule<DB> {
         database: &Self::Database,
         table: &<Self::Database as DatabaseLike>::Table,
     ) -> Result<(), crate::error::Error<DB>> {
-        let mut constraints = table.check_constraints(database).collect::<Vec<_>>();
-        constraints.sort_unstable_by_key(|c| c.expression(database));
-        for window in constraints.windows(2) {
-            if window[0].expression(database) == window[1].expression(database) {
-                let error: RuleErrorInfo = RuleErrorInfo::builder()
-                    .rule("UniqueCheckConstraint")
-                    .unwrap()
-                    .object(table.table_name().to_owned())
-                    .unwrap()
-                    .message(format!(
-                        "Table '{}' has non-unique check constraints",
-                        table.table_name()
-                    ))
-                    .unwrap()
-                    .resolution("Ensure all check constraints in the table are unique".to_string())
-                    .unwrap()
-                    .try_into()
-                    .unwrap();
-                return Err(crate::error::Error::Table(
-                    Box::new(table.clone()),
-                    error.into(),
-                ));
-            }
+        let constraints = table
+            .check_constraints(database)
+            .map(|constraint| (constraint, canonical_form(constraint.expression(database))))
+            .collect::<Vec<_>>();
+        for (position, (constraint, canonical)) in constraints.iter().enumerate() {
+            let Some((original, _)) = constraints[..position]
+                .iter()
+                .find(|(_, other_canonical)| other_canonical == canonical)
+            else {
+                continue;
+            };
+            let duplicate_description = ObjectRef::describe_check_constraint(database, *constraint);
+            let original_description = ObjectRef::describe_check_constraint(database, *original);
+            let error: RuleErrorInfo = RuleErrorInfo::builder()
+                .rule("UniqueCheckConstraint")
+                .unwrap()
+                .object(ObjectRef::check_constraint(
+                    table,
+                    constraint.check_constraint_name(),
+                    constraint.expression(database),
+                ))
+                .unwrap()
+                .message(format!(
+                    "Table '{}' has non-unique check constraints: {duplicate_description} is equivalent to {original_description}",
+                    table.table_name()
+                ))
+                .unwrap()
+                .resolution(format!(
+                    "Drop check constraint {duplicate_description}, which duplicates {original_description}"
+                ))
+                .unwrap()
+                .try_into()
+                .unwrap();
+            return Err(crate::error::Error::Table(
+                Box::new(table.clone()),
+                error.into(),
+            ));
         }
         Ok(())
     }
@@ -6,7 +6,7 @@ use std::collections::HashSet;
 use sql_traits::traits::{ColumnLike, DatabaseLike, TableLike};
 
 use crate::{
-    error::RuleErrorInfo,
+    error::{ObjectRef, RuleErrorInfo},
     traits::{Constrainer, GenericConstrainer, TableRule},
 };
 
@@ -215,7 +215,7 @@ impl<DB: DatabaseLike> TableRule for UniqueColumnNamesInExtensionGraph<DB> {
             let error: RuleErrorInfo = RuleErrorInfo::builder()
                 .rule("UniqueColumnNamesInExtensionGraph")
                 .unwrap()
-                .object(table.table_name().to_owned())
+                .object(ObjectRef::table(table))
                 .unwrap()
                 .message(format!(
                     "Table '{}' has {} duplicate column name(s) that conflict with parent tables: {}",
@@ -7,7 +7,7 @@ use std::hash::{DefaultHasher, Hash, Hasher};
 use sql_traits::traits::{ColumnLike, DatabaseLike, ForeignKeyLike, TableLike};
 
 use crate::{
-    error::RuleErrorInfo,
+    error::{ObjectRef, RuleErrorInfo},
     traits::{Constrainer, GenericConstrainer, TableRule},
 };
 
@@ -107,7 +107,7 @@ impl<DB: DatabaseLike> TableRule for UniqueForeignKey<DB> {
                 RuleErrorInfo::builder()
                     .rule("UniqueForeignKey")
                     .unwrap()
-                    .object(table_name.to_owned())
+                    .object(ObjectRef::table(table))
                     .unwrap()
                     .message(format!("Table '{table_name}' has duplicate foreign keys"))
                     .unwrap()
@@ -153,7 +153,7 @@ impl<DB: DatabaseLike> TableRule for UniqueForeignKey<DB> {
                 RuleErrorInfo::builder()
                     .rule("UniqueForeignKey")
                     .unwrap()
-                    .object(table_name.to_owned())
+                    .object(ObjectRef::foreign_key(database, duplicate_fks[1]))
                     .unwrap()
                     .message(message)
                     .unwrap()
@@ -3,7 +3,7 @@
 //! constraints have the same clause in a single table.
 
 use crate::{
-    error::RuleErrorInfo,
+    error::{ObjectRef, RuleErrorInfo},
     traits::{Constrainer, GenericConstrainer, TableRule},
 };
 use sql_traits::traits::IndexLike;
@@ -61,7 +61,11 @@ impl<DB: DatabaseLike> TableRule for UniqueUniqueIndex<DB> {
                 let error: RuleErrorInfo = RuleErrorInfo::builder()
                     .rule("UniqueUniqueIndex")
                     .unwrap()
-                    .object(table.table_name().to_owned())
+                    .object(ObjectRef::index(
+                        table,
+                        window[1].index_name(),
+                        duplicate_expression,
+                    ))
                     .unwrap()
                     .message(format!(
                         "Table '{}' has non-unique unique index on columns: {}",
@@ -10,3 +10,5 @@ mod rule_failure_information;
 pub use rule_failure_information::RuleFailureInformation;
 mod foreign_key_rule;
 pub use foreign_key_rule::ForeignKeyRule;
+mod database_rule;
+pub use database_rule::DatabaseRule;
@@ -3,7 +3,7 @@
 
 use crate::{
     error::Error,
-    traits::{ColumnRule, ForeignKeyRule, TableRule},
+    traits::{ColumnRule, DatabaseRule, ForeignKeyRule, TableRule},
 };
 
 pub mod generic_constrainer;
@@ -29,6 +29,9 @@ pub trait Constrainer: Default {
         rule: Box<dyn ForeignKeyRule<Database = Self::Database>>,
     );
 
+    /// Registers a database rule to be applied once to the whole database.
+    fn register_database_rule(&mut self, rule: Box<dyn DatabaseRule<Database = Self::Database>>);
+
     /// Returns an iterator over all registered table rules.
     fn table_rules(&self) -> impl Iterator<Item = &dyn TableRule<Database = Self::Database>>;
 
@@ -40,6 +43,9 @@ pub trait Constrainer: Default {
         &self,
     ) -> impl Iterator<Item = &dyn ForeignKeyRule<Database = Self::Database>>;
 
+    /// Returns an iterator over all registered database rules.
+    fn database_rules(&self) -> impl Iterator<Item = &dyn DatabaseRule<Database = Self::Database>>;
+
     /// Encounters a table and applies all registered table rules to it.
     ///
     /// # Errors
@@ -83,8 +89,19 @@ pub trait Constrainer: Default {
             .try_for_each(|constraint| constraint.validate_foreign_key(database, foreign_key))
     }
 
+    /// Encounters the database and applies all registered database rules to
+    /// it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any database rule is violated.
+    fn encounter_database(&self, database: &Self::Database) -> Result<(), Error<Self::Database>> {
+        self.database_rules()
+            .try_for_each(|constraint| constraint.validate_database(database))
+    }
+
     /// Validates the provided schema by applying all registered rules to
-    /// its DB entities.
+    /// its DB entities, and then the database rules to the whole database.
     ///
     /// # Errors
     ///
@@ -99,6 +116,6 @@ pub trait Constrainer: Default {
                 self.encounter_foreign_key(database, foreign_key)?;
             }
         }
-        Ok(())
+        self.encounter_database(database)
     }
 }
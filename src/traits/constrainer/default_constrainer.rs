@@ -4,17 +4,15 @@ use sql_traits::traits::DatabaseLike;
 
 use crate::{
     prelude::{
-        ExtensionForeignKeyOnDeleteCascade, NoNegationCheckRule, NoRustKeywordColumnName,
-        NoRustKeywordForeignKeyName, NoRustKeywordTableName, NoTautologicalCheckRule,
-        PrimaryKeyReferenceEndsWithId, ReferencesUniqueIndex,
+        ExtensionForeignKeyOnDeleteCascade, NoNegationCheckRule, NoReservedIdentifier,
+        NoTautologicalCheckRule, PrimaryKeyReferenceEndsWithId, ReferencesUniqueIndex,
     },
     rules::{
-        CompatibleForeignKey, HasPrimaryKey, LowercaseColumnName, LowercaseForeignKeyName,
-        LowercaseTableName, NoForbiddenColumnInExtension, NoSurrogatePrimaryKeyInExtension,
-        NonCompositePrimaryKeyNamedId, NonRedundantExtensionDag, PastTimeColumnRule,
-        PluralTableName, PoliciesRequireRowLevelSecurity, SingularColumnName, SnakeCaseColumnName,
-        SnakeCaseTableName, TextualColumnRule, UniqueCheckRule, UniqueColumnNamesInExtensionGraph,
-        UniqueForeignKey, UniqueUniqueIndex,
+        CompatibleForeignKey, HasPrimaryKey, NamingConvention, NoForbiddenColumnInExtension,
+        NoSurrogatePrimaryKeyInExtension, NonCompositePrimaryKeyNamedId, NonRedundantExtensionDag,
+        PastTimeColumnRule, PluralTableName, PoliciesRequireRowLevelSecurity, SingularColumnName,
+        TextualColumnRule, UniqueCheckRule, UniqueColumnNamesInExtensionGraph, UniqueForeignKey,
+        UniqueUniqueIndex,
     },
     traits::Constrainer,
 };
@@ -29,10 +27,11 @@ use crate::{
 ///
 /// ## Table Constraints
 /// - [`HasPrimaryKey`]: Ensures all tables have a primary key
-/// - [`LowercaseTableName`]: Ensures table names are lowercase
-/// - [`SnakeCaseTableName`]: Ensures table names follow `snake_case` convention
+/// - [`NamingConvention`]: Ensures table, check constraint and index names
+///   follow `snake_case` convention
 /// - [`PluralTableName`]: Ensures table names are plural
 /// - [`PoliciesRequireRowLevelSecurity`]: Ensures tables with policies have RLS enabled
+/// - [`NoReservedIdentifier`]: Ensures table names are not Rust keywords
 /// - [`NoForbiddenColumnInExtension`]: Prevents forbidden columns in extended
 ///   tables
 /// - [`NonRedundantExtensionDag`]: Ensures no redundant edges in extension
@@ -44,20 +43,22 @@ use crate::{
 /// - [`UniqueUniqueIndex`]: Ensures unique index names are unique
 ///
 /// ## Column Constraints
-/// - [`LowercaseColumnName`]: Ensures column names are lowercase
 /// - [`NonCompositePrimaryKeyNamedId`]: Ensures non-composite primary keys are
 ///   named "id"
 /// - [`NoSurrogatePrimaryKeyInExtension`]: Ensures extension-table primary
 ///   keys are not surrogate
-/// - [`SnakeCaseColumnName`]: Ensures column names follow `snake_case`
+/// - [`NamingConvention`]: Ensures column names follow `snake_case`
 ///   convention
 /// - [`SingularColumnName`]: Ensures column names are singular
+/// - [`NoReservedIdentifier`]: Ensures column names are not Rust keywords
 /// - [`TextualColumnRule`]: Ensures textual columns have content and length checks
 /// - [`PastTimeColumnRule`]: Ensures time-related columns are in the past
 ///
 /// ## Foreign Key Constraints
 /// - [`CompatibleForeignKey`]: Ensures foreign key columns are type-compatible
-/// - [`LowercaseForeignKeyName`]: Ensures foreign key names are lowercase
+/// - [`NamingConvention`]: Ensures foreign key names follow `snake_case`
+///   convention
+/// - [`NoReservedIdentifier`]: Ensures foreign key names are not Rust keywords
 ///
 /// # Example
 ///
@@ -81,11 +82,10 @@ where
 
         // Register all table constraints
         constrainer.register_table_rule(Box::new(HasPrimaryKey::default()));
-        constrainer.register_table_rule(Box::new(LowercaseTableName::default()));
-        constrainer.register_table_rule(Box::new(SnakeCaseTableName::default()));
+        constrainer.register_table_rule(Box::new(NamingConvention::default()));
         constrainer.register_table_rule(Box::new(PluralTableName::default()));
         constrainer.register_table_rule(Box::new(PoliciesRequireRowLevelSecurity::default()));
-        constrainer.register_table_rule(Box::new(NoRustKeywordTableName::default()));
+        constrainer.register_table_rule(Box::new(NoReservedIdentifier::default()));
         constrainer.register_table_rule(Box::new(NoTautologicalCheckRule::default()));
         constrainer.register_table_rule(Box::new(NoNegationCheckRule::default()));
         constrainer.register_table_rule(Box::new(NoForbiddenColumnInExtension::new(
@@ -98,23 +98,22 @@ where
         constrainer.register_table_rule(Box::new(UniqueUniqueIndex::default()));
 
         // Register all column constraints
-        constrainer.register_column_rule(Box::new(LowercaseColumnName::default()));
         constrainer.register_column_rule(Box::new(NonCompositePrimaryKeyNamedId::default()));
         constrainer.register_column_rule(Box::new(NoSurrogatePrimaryKeyInExtension::default()));
-        constrainer.register_column_rule(Box::new(SnakeCaseColumnName::default()));
+        constrainer.register_column_rule(Box::new(NamingConvention::default()));
         constrainer.register_column_rule(Box::new(SingularColumnName::default()));
-        constrainer.register_column_rule(Box::new(NoRustKeywordColumnName::default()));
+        constrainer.register_column_rule(Box::new(NoReservedIdentifier::default()));
         constrainer.register_column_rule(Box::new(TextualColumnRule::default()));
         constrainer.register_column_rule(Box::new(PastTimeColumnRule::default()));
 
         // Register all foreign key constraints
         constrainer.register_foreign_key_rule(Box::new(CompatibleForeignKey::default()));
-        constrainer.register_foreign_key_rule(Box::new(LowercaseForeignKeyName::default()));
+        constrainer.register_foreign_key_rule(Box::new(NamingConvention::default()));
         constrainer.register_foreign_key_rule(Box::new(ReferencesUniqueIndex::default()));
         constrainer.register_foreign_key_rule(Box::new(PrimaryKeyReferenceEndsWithId::default()));
         constrainer
             .register_foreign_key_rule(Box::new(ExtensionForeignKeyOnDeleteCascade::default()));
-        constrainer.register_foreign_key_rule(Box::new(NoRustKeywordForeignKeyName::default()));
+        constrainer.register_foreign_key_rule(Box::new(NoReservedIdentifier::default()));
 
         Self { constrainer }
     }
@@ -144,6 +143,12 @@ where
         self.constrainer.foreign_key_rules()
     }
 
+    fn database_rules(
+        &self,
+    ) -> impl Iterator<Item = &dyn crate::traits::DatabaseRule<Database = Self::Database>> {
+        self.constrainer.database_rules()
+    }
+
     fn register_table_rule(
         &mut self,
         rule: Box<dyn crate::traits::TableRule<Database = Self::Database>>,
@@ -164,4 +169,11 @@ where
     ) {
         self.constrainer.register_foreign_key_rule(rule);
     }
+
+    fn register_database_rule(
+        &mut self,
+        rule: Box<dyn crate::traits::DatabaseRule<Database = Self::Database>>,
+    ) {
+        self.constrainer.register_database_rule(rule);
+    }
 }
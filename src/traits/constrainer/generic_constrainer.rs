@@ -12,6 +12,8 @@ pub struct GenericConstrainer<DB: DatabaseLike> {
     columns: Vec<Box<dyn crate::traits::ColumnRule<Database = DB>>>,
     /// The registered foreign key rules.
     foreign_keys: Vec<Box<dyn crate::traits::ForeignKeyRule<Database = DB>>>,
+    /// The registered database rules.
+    databases: Vec<Box<dyn crate::traits::DatabaseRule<Database = DB>>>,
 }
 
 impl<DB: DatabaseLike> Default for GenericConstrainer<DB> {
@@ -20,6 +22,7 @@ impl<DB: DatabaseLike> Default for GenericConstrainer<DB> {
             tables: Vec::new(),
             columns: Vec::new(),
             foreign_keys: Vec::new(),
+            databases: Vec::new(),
         }
     }
 }
@@ -45,6 +48,12 @@ impl<DB: DatabaseLike> Constrainer for GenericConstrainer<DB> {
         self.foreign_keys.iter().map(AsRef::as_ref)
     }
 
+    fn database_rules(
+        &self,
+    ) -> impl Iterator<Item = &dyn crate::traits::DatabaseRule<Database = Self::Database>> {
+        self.databases.iter().map(AsRef::as_ref)
+    }
+
     fn register_table_rule(
         &mut self,
         rule: Box<dyn crate::traits::TableRule<Database = Self::Database>>,
@@ -65,4 +74,11 @@ impl<DB: DatabaseLike> Constrainer for GenericConstrainer<DB> {
     ) {
         self.foreign_keys.push(rule);
     }
+
+    fn register_database_rule(
+        &mut self,
+        rule: Box<dyn crate::traits::DatabaseRule<Database = Self::Database>>,
+    ) {
+        self.databases.push(rule);
+    }
 }
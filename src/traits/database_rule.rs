@@ -0,0 +1,20 @@
+//! Submodule defining the `DatabaseRule` trait, which defines a rule
+//! which applies to a whole database at once.
+
+use sql_traits::traits::DatabaseLike;
+
+use crate::error::Error;
+
+/// Trait for types that define a database rule object, which compares
+/// objects across the whole database and should therefore visit it once.
+pub trait DatabaseRule {
+    /// The database type that this rule applies to.
+    type Database: DatabaseLike;
+
+    /// Validates that the given database satisfies the rule.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database violates this rule.
+    fn validate_database(&self, database: &Self::Database) -> Result<(), Error<Self::Database>>;
+}
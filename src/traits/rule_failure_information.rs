@@ -3,13 +3,15 @@
 
 use std::fmt::{Debug, Display};
 
+use crate::error::ObjectRef;
+
 /// Trait for types that provide information about a rule failure.
 pub trait RuleFailureInformation: Display + Debug {
     /// Type of rule which failed.
     fn rule(&self) -> &'static str;
 
     /// DB object which failed the rule.
-    fn object(&self) -> &str;
+    fn object(&self) -> &ObjectRef;
 
     /// Error message describing the failure.
     fn message(&self) -> &str;